@@ -0,0 +1,60 @@
+use core::f64::consts::TAU;
+
+/// An angle, stored as `f64` radians. Construct it with [Angle::from_degrees] or
+/// [Angle::from_radians] so the unit is always explicit.
+///
+/// Like [Cardinal::to_angle], angles are mathematical: east is 0 and they grow counter-clockwise.
+///
+/// [Cardinal::to_angle]: crate::Cardinal::to_angle
+#[derive(Debug, PartialEq, Clone, Copy, PartialOrd, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Angle(f64);
+
+impl Angle {
+    /// An angle of zero, pointing east.
+    pub const ZERO: Angle = Angle(0.0);
+
+    /// Creates an angle from radians.
+    pub const fn from_radians(radians: f64) -> Self {
+        Self(radians)
+    }
+
+    /// Creates an angle from degrees.
+    pub fn from_degrees(degrees: f64) -> Self {
+        Self(degrees.to_radians())
+    }
+
+    /// Returns the angle in radians.
+    pub fn radians(self) -> f64 {
+        self.0
+    }
+
+    /// Returns the angle in degrees.
+    pub fn degrees(self) -> f64 {
+        self.0.to_degrees()
+    }
+
+    /// Is neither infinite nor NaN.
+    pub fn is_finite(self) -> bool {
+        self.0.is_finite()
+    }
+
+    /// Returns the same direction as an angle in `0..TAU` radians.
+    #[must_use = "this returns the result of the operation, \
+    without modifying the original"]
+    pub fn normalized(self) -> Self {
+        Self(self.0.rem_euclid(TAU))
+    }
+}
+
+/// The error returned when converting an infinite or NaN [Angle] into a direction.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub struct NonFiniteAngle;
+
+impl core::fmt::Display for NonFiniteAngle {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str("angle is infinite or NaN, so it has no direction")
+    }
+}
+
+impl std::error::Error for NonFiniteAngle {}
@@ -0,0 +1,189 @@
+use core::{cmp::Ordering, iter::Sum, ops};
+
+use crate::{Cardinal, CardinalValues};
+
+macro_rules! impl_elementwise {
+    ($trait:ident, $method:ident, $assign_trait:ident, $assign_method:ident) => {
+        impl<T: ops::$trait<U>, U> ops::$trait<CardinalValues<U>> for CardinalValues<T> {
+            type Output = CardinalValues<T::Output>;
+
+            fn $method(self, rhs: CardinalValues<U>) -> Self::Output {
+                CardinalValues {
+                    east: self.east.$method(rhs.east),
+                    north: self.north.$method(rhs.north),
+                    west: self.west.$method(rhs.west),
+                    south: self.south.$method(rhs.south),
+                }
+            }
+        }
+
+        impl<T: ops::$assign_trait<U>, U> ops::$assign_trait<CardinalValues<U>>
+            for CardinalValues<T>
+        {
+            fn $assign_method(&mut self, rhs: CardinalValues<U>) {
+                self.east.$assign_method(rhs.east);
+                self.north.$assign_method(rhs.north);
+                self.west.$assign_method(rhs.west);
+                self.south.$assign_method(rhs.south);
+            }
+        }
+    };
+}
+
+impl_elementwise!(Add, add, AddAssign, add_assign);
+impl_elementwise!(Sub, sub, SubAssign, sub_assign);
+impl_elementwise!(Mul, mul, MulAssign, mul_assign);
+impl_elementwise!(Div, div, DivAssign, div_assign);
+
+// scalar operators can't be generic over `T`, since `CardinalValues<T>` is itself a `T`,
+// so they're written out for each primitive.
+macro_rules! impl_scalar {
+    ($($scalar:ty),*) => {$(
+        impl_scalar!(@op $scalar, Add, add, AddAssign, add_assign);
+        impl_scalar!(@op $scalar, Sub, sub, SubAssign, sub_assign);
+        impl_scalar!(@op $scalar, Mul, mul, MulAssign, mul_assign);
+        impl_scalar!(@op $scalar, Div, div, DivAssign, div_assign);
+
+        impl ops::Mul<CardinalValues<$scalar>> for $scalar {
+            type Output = CardinalValues<$scalar>;
+
+            fn mul(self, rhs: CardinalValues<$scalar>) -> Self::Output {
+                rhs * self
+            }
+        }
+    )*};
+    (@op $scalar:ty, $trait:ident, $method:ident, $assign_trait:ident, $assign_method:ident) => {
+        impl ops::$trait<$scalar> for CardinalValues<$scalar> {
+            type Output = Self;
+
+            fn $method(self, rhs: $scalar) -> Self::Output {
+                self.map(|v| ops::$trait::$method(v, rhs))
+            }
+        }
+
+        impl ops::$assign_trait<$scalar> for CardinalValues<$scalar> {
+            fn $assign_method(&mut self, rhs: $scalar) {
+                ops::$assign_trait::$assign_method(&mut self.east, rhs);
+                ops::$assign_trait::$assign_method(&mut self.north, rhs);
+                ops::$assign_trait::$assign_method(&mut self.west, rhs);
+                ops::$assign_trait::$assign_method(&mut self.south, rhs);
+            }
+        }
+    };
+}
+
+impl_scalar!(f32, f64, i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+impl<T: ops::Neg> ops::Neg for CardinalValues<T> {
+    type Output = CardinalValues<T::Output>;
+
+    fn neg(self) -> Self::Output {
+        self.map(|v| -v)
+    }
+}
+
+impl<T: ops::Add<Output = T> + Default> Sum for CardinalValues<T> {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::default(), |acc, v| acc + v)
+    }
+}
+
+impl<'a, T: ops::Add<Output = T> + Default + Copy> Sum<&'a CardinalValues<T>>
+    for CardinalValues<T>
+{
+    fn sum<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+        iter.copied().sum()
+    }
+}
+
+impl<T> CardinalValues<T> {
+    /// Adds the east and west values together.
+    pub fn horizontal(self) -> T
+    where
+        T: ops::Add<Output = T>,
+    {
+        self.east + self.west
+    }
+
+    /// Adds the north and south values together.
+    pub fn vertical(self) -> T
+    where
+        T: ops::Add<Output = T>,
+    {
+        self.north + self.south
+    }
+
+    /// Adds all four values together.
+    pub fn total(self) -> T
+    where
+        T: ops::Add<Output = T>,
+    {
+        self.east + self.north + self.west + self.south
+    }
+
+    /// Returns the smallest value. See [CardinalValues::argmin] for how ties are broken.
+    pub fn min_value(self) -> T
+    where
+        T: PartialOrd,
+    {
+        let side = self.argmin();
+        self.into_value(side)
+    }
+
+    /// Returns the largest value. See [CardinalValues::argmax] for how ties are broken.
+    pub fn max_value(self) -> T
+    where
+        T: PartialOrd,
+    {
+        let side = self.argmax();
+        self.into_value(side)
+    }
+
+    /// Returns the side with the smallest value. Ties go to the first side in the order
+    /// east, north, west, south, and values which can't be compared, such as NaN, are only
+    /// picked if every value is like that.
+    pub fn argmin(&self) -> Cardinal
+    where
+        T: PartialOrd,
+    {
+        self.extreme(Ordering::Less)
+    }
+
+    /// Returns the side with the largest value. Ties go to the first side in the order
+    /// east, north, west, south, and values which can't be compared, such as NaN, are only
+    /// picked if every value is like that.
+    pub fn argmax(&self) -> Cardinal
+    where
+        T: PartialOrd,
+    {
+        self.extreme(Ordering::Greater)
+    }
+
+    fn extreme(&self, wanted: Ordering) -> Cardinal
+    where
+        T: PartialOrd,
+    {
+        let mut best = Cardinal::East;
+        for side in [Cardinal::North, Cardinal::West, Cardinal::South] {
+            let replace = match self[side].partial_cmp(&self[best]) {
+                Some(ordering) => ordering == wanted,
+                // only replace the best if it can't be compared with anything, such as NaN.
+                None => self[best].partial_cmp(&self[best]).is_none(),
+            };
+            if replace {
+                best = side;
+            }
+        }
+
+        best
+    }
+
+    fn into_value(self, side: Cardinal) -> T {
+        match side {
+            Cardinal::East => self.east,
+            Cardinal::North => self.north,
+            Cardinal::West => self.west,
+            Cardinal::South => self.south,
+        }
+    }
+}
@@ -0,0 +1,127 @@
+//! Bitmask autotiling for the standard 16 tile and 47 tile "blob" layouts.
+//!
+//! Both layouts number the neighbours clockwise from north. For the 16 tile layout,
+//! each side has one bit, and the tile index is the sum of the bits:
+//!
+//! | north | east | south | west |
+//! |-------|------|-------|------|
+//! | 1     | 2    | 4     | 8    |
+//!
+//! The blob layout adds a bit for each corner:
+//!
+//! | north | north east | east | south east | south | south west | west | north west |
+//! |-------|------------|------|------------|-------|------------|------|------------|
+//! | 1     | 2          | 4    | 8          | 16    | 32         | 64   | 128        |
+//!
+//! A corner only counts when both of the sides next to it are also set, since otherwise
+//! it can't be seen in the tile. That leaves 47 distinct masks, and the blob index of a tile
+//! is the position of its mask in [BLOB_MASKS].
+
+use crate::{Cardinal, CardinalValues, Corner, CornerValues};
+
+/// Every masked 8-bit value in the blob layout, in ascending order. The blob index of a mask
+/// is its position in this table.
+pub const BLOB_MASKS: [u8; 47] = [
+    0, 1, 4, 5, 7, 16, 17, 20, 21, 23, 28, 29, 31, 64, 65, 68, 69, 71, 80, 81, 84, 85, 87, 92, 93,
+    95, 112, 113, 116, 117, 119, 124, 125, 127, 193, 197, 199, 209, 213, 215, 221, 223, 241, 245,
+    247, 253, 255,
+];
+
+fn side_bit_4(cardinal: Cardinal) -> u8 {
+    match cardinal {
+        Cardinal::North => 1,
+        Cardinal::East => 2,
+        Cardinal::South => 4,
+        Cardinal::West => 8,
+    }
+}
+
+fn side_bit_8(cardinal: Cardinal) -> u8 {
+    match cardinal {
+        Cardinal::North => 1,
+        Cardinal::East => 4,
+        Cardinal::South => 16,
+        Cardinal::West => 64,
+    }
+}
+
+fn corner_bit_8(corner: Corner) -> u8 {
+    match corner {
+        Corner::NorthEast => 2,
+        Corner::SouthEast => 8,
+        Corner::SouthWest => 32,
+        Corner::NorthWest => 128,
+    }
+}
+
+/// Returns the index in `0..16` of a tile in the 16 tile layout.
+pub fn index_4bit(sides: CardinalValues<bool>) -> u8 {
+    Cardinal::iter_values()
+        .filter(|v| sides[*v])
+        .map(side_bit_4)
+        .sum()
+}
+
+/// Returns the sides of a tile in the 16 tile layout from its index. Returns `None` if
+/// the index is not in `0..16`.
+pub fn sides_4bit(index: u8) -> Option<CardinalValues<bool>> {
+    if index >= 16 {
+        return None;
+    }
+
+    Some(CardinalValues {
+        east: index & side_bit_4(Cardinal::East) != 0,
+        north: index & side_bit_4(Cardinal::North) != 0,
+        west: index & side_bit_4(Cardinal::West) != 0,
+        south: index & side_bit_4(Cardinal::South) != 0,
+    })
+}
+
+/// Returns the 8-bit mask of a tile in the blob layout, with corners that can't be seen
+/// masked out. This is always one of the values in [BLOB_MASKS].
+pub fn blob_mask(sides: CardinalValues<bool>, corners: CornerValues<bool>) -> u8 {
+    let side_bits: u8 = Cardinal::iter_values()
+        .filter(|v| sides[*v])
+        .map(side_bit_8)
+        .sum();
+
+    let corner_bits: u8 = Corner::iter_values()
+        .filter(|v| {
+            let (a, b) = v.split();
+            corners[*v] && sides[a] && sides[b]
+        })
+        .map(corner_bit_8)
+        .sum();
+
+    side_bits | corner_bits
+}
+
+/// Returns the index in `0..47` of a tile in the blob layout.
+pub fn blob_index(sides: CardinalValues<bool>, corners: CornerValues<bool>) -> u8 {
+    let mask = blob_mask(sides, corners);
+
+    BLOB_MASKS
+        .binary_search(&mask)
+        .expect("masked values are always in the table") as u8
+}
+
+/// Returns the sides and corners of a tile in the blob layout from its index. Corners which
+/// are masked out are given as `false`. Returns `None` if the index is not in `0..47`.
+pub fn blob_neighbors(index: u8) -> Option<(CardinalValues<bool>, CornerValues<bool>)> {
+    let mask = *BLOB_MASKS.get(index as usize)?;
+
+    let sides = CardinalValues {
+        east: mask & side_bit_8(Cardinal::East) != 0,
+        north: mask & side_bit_8(Cardinal::North) != 0,
+        west: mask & side_bit_8(Cardinal::West) != 0,
+        south: mask & side_bit_8(Cardinal::South) != 0,
+    };
+    let corners = CornerValues {
+        north_east: mask & corner_bit_8(Corner::NorthEast) != 0,
+        north_west: mask & corner_bit_8(Corner::NorthWest) != 0,
+        south_west: mask & corner_bit_8(Corner::SouthWest) != 0,
+        south_east: mask & corner_bit_8(Corner::SouthEast) != 0,
+    };
+
+    Some((sides, corners))
+}
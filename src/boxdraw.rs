@@ -0,0 +1,173 @@
+//! Conversions between connectivity masks and box-drawing characters, for debug output of
+//! mazes, pipes and wires.
+//!
+//! A mask is a `CardinalValues<bool>`, where each `true` side has a line running out of it.
+
+use crate::{Cardinal, CardinalSet, CardinalValues, CoordinateSystem, Grid};
+
+/// The set of characters used to draw a mask.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, PartialOrd, Ord, Default, Hash)]
+pub enum BoxStyle {
+    /// Thin lines, such as `┼`.
+    #[default]
+    Light,
+    /// Thick lines, such as `╋`.
+    Heavy,
+    /// Double lines, such as `╬`. Unicode has no double line ends, so a mask with a single
+    /// side is drawn as a full line through the cell.
+    Double,
+    /// Plain ASCII, using `-`, `|` and `+`. Every bend and junction is drawn as `+`, and
+    /// line ends are drawn as full lines.
+    Ascii,
+}
+
+// each table is indexed by `CardinalSet::index`, so east is bit 0, north bit 1, west bit 2
+// and south bit 3.
+const LIGHT: [char; 16] = [
+    ' ', '╶', '╵', '└', '╴', '─', '┘', '┴', '╷', '┌', '│', '├', '┐', '┬', '┤', '┼',
+];
+const HEAVY: [char; 16] = [
+    ' ', '╺', '╹', '┗', '╸', '━', '┛', '┻', '╻', '┏', '┃', '┣', '┓', '┳', '┫', '╋',
+];
+const DOUBLE: [char; 16] = [
+    ' ', '═', '║', '╚', '═', '═', '╝', '╩', '║', '╔', '║', '╠', '╗', '╦', '╣', '╬',
+];
+const ASCII: [char; 16] = [
+    ' ', '-', '|', '+', '-', '-', '+', '+', '|', '+', '|', '+', '+', '+', '+', '+',
+];
+
+impl BoxStyle {
+    fn table(self) -> &'static [char; 16] {
+        match self {
+            BoxStyle::Light => &LIGHT,
+            BoxStyle::Heavy => &HEAVY,
+            BoxStyle::Double => &DOUBLE,
+            BoxStyle::Ascii => &ASCII,
+        }
+    }
+}
+
+/// Returns the character which draws a mask in the given style.
+pub fn to_char(mask: CardinalValues<bool>, style: BoxStyle) -> char {
+    style.table()[CardinalSet::from(mask).index()]
+}
+
+/// Returns the mask a character draws, in any style. Returns `None` if the character isn't
+/// one of the box-drawing characters used by [BoxStyle].
+///
+/// Characters which several masks share, such as `═` or `+`, give the mask with the most
+/// sides, so `═` is east and west, and `+` is all four.
+pub fn from_char(c: char) -> Option<CardinalValues<bool>> {
+    // searching from the top finds the fullest mask first.
+    (0..16)
+        .rev()
+        .find(|i| [LIGHT, HEAVY, DOUBLE, ASCII].iter().any(|t| t[*i] == c))
+        .and_then(CardinalSet::from_index)
+        .map(CardinalValues::from)
+}
+
+/// Draws a grid of masks, one character per cell and one line per row, with the northern
+/// row first. Every row, including the last, ends in a newline.
+pub fn render<C: CoordinateSystem>(
+    grid: &Grid<CardinalValues<bool>, C>,
+    style: BoxStyle,
+) -> String {
+    let mut output = String::new();
+    for row in grid.lines_from(Cardinal::North) {
+        output.extend(row.map(|(_, mask)| to_char(*mask, style)));
+        output.push('\n');
+    }
+
+    output
+}
+
+/// Reads a diagram, as drawn by [render], back into a grid of masks. The first line is
+/// the northern row, and short lines are padded with empty cells.
+///
+/// A `+` only connects to the sides whose neighbours connect back to it, so ASCII
+/// diagrams keep their bends and junctions.
+///
+/// ```
+/// # use cardinal_values::GridPos;
+/// # use cardinal_values::boxdraw::{self, BoxStyle};
+/// let diagram = "┌─┬┐\n│ ││\n└─┴┘\n";
+/// let grid = boxdraw::parse(diagram).unwrap();
+/// assert_eq!(boxdraw::render(&grid, BoxStyle::Light), diagram);
+///
+/// // the top left corner is in the northern row, and runs east and south.
+/// let corner = grid[GridPos::new(0, 2)];
+/// assert!(corner.east && corner.south && !corner.north && !corner.west);
+///
+/// // without dead ends, every style reads back to the same connections.
+/// for style in [BoxStyle::Light, BoxStyle::Heavy, BoxStyle::Double, BoxStyle::Ascii] {
+///     let drawn = boxdraw::render(&grid, style);
+///     assert_eq!(boxdraw::parse(&drawn).unwrap(), grid, "{:?}:\n{}", style, drawn);
+/// }
+/// assert_eq!(boxdraw::render(&grid, BoxStyle::Ascii), "+-++\n| ||\n+-++\n");
+/// ```
+pub fn parse(diagram: &str) -> Result<Grid<CardinalValues<bool>>, ParseBoxError> {
+    let rows: Vec<Vec<char>> = diagram.lines().map(|v| v.chars().collect()).collect();
+
+    for (line, row) in rows.iter().enumerate() {
+        if let Some((column, found)) = row
+            .iter()
+            .enumerate()
+            .find(|(_, c)| from_char(**c).is_none())
+        {
+            return Err(ParseBoxError {
+                found: *found,
+                line,
+                column,
+            });
+        }
+    }
+
+    let height = rows.len() as u32;
+    let width = rows.iter().map(|v| v.len()).max().unwrap_or(0) as u32;
+    let chars = Grid::from_fn(width, height, |pos| {
+        rows[(height as i32 - 1 - pos.y) as usize]
+            .get(pos.x as usize)
+            .copied()
+            .unwrap_or(' ')
+    });
+    let masks = Grid::from_fn(width, height, |pos| {
+        from_char(chars[pos]).unwrap_or_default()
+    });
+
+    Ok(Grid::from_fn(width, height, |pos| {
+        if chars[pos] != '+' {
+            return masks[pos];
+        }
+
+        let neighbors = masks.neighbors(pos);
+        CardinalValues {
+            east: neighbors.east.is_some_and(|v| v.west),
+            north: neighbors.north.is_some_and(|v| v.south),
+            west: neighbors.west.is_some_and(|v| v.east),
+            south: neighbors.south.is_some_and(|v| v.north),
+        }
+    }))
+}
+
+/// The error returned when [parse] finds a character which isn't a box-drawing character.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub struct ParseBoxError {
+    /// The character which was found.
+    pub found: char,
+    /// The line it was found on, counting from zero.
+    pub line: usize,
+    /// The character offset into the line, counting from zero.
+    pub column: usize,
+}
+
+impl core::fmt::Display for ParseBoxError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "{:?} at line {}, column {} is not a box-drawing character",
+            self.found, self.line, self.column
+        )
+    }
+}
+
+impl std::error::Error for ParseBoxError {}
@@ -0,0 +1,77 @@
+/// A convention for which way the axes point, used by the `_in` variants of the vector and
+/// angle conversions, such as [Cardinal::to_ivec2_in] and [Cardinal::to_angle_in].
+///
+/// The conversions without a suffix, such as [Cardinal::to_ivec2], always use [YUp].
+///
+/// [Cardinal::to_ivec2_in]: crate::Cardinal::to_ivec2_in
+/// [Cardinal::to_angle_in]: crate::Cardinal::to_angle_in
+/// [Cardinal::to_ivec2]: crate::Cardinal::to_ivec2
+pub trait CoordinateSystem {
+    /// North points towards negative y, as in most tile map formats and image coordinates.
+    const Y_DOWN: bool;
+
+    /// Angles grow clockwise, when drawn with north at the top. East is always at 0 degrees.
+    const CLOCKWISE: bool;
+}
+
+/// The mathematical convention. North is `(0, 1)`, and angles grow counter-clockwise,
+/// so north is at 90 degrees. This is the default.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, PartialOrd, Ord, Default, Hash)]
+pub struct YUp;
+
+impl CoordinateSystem for YUp {
+    const Y_DOWN: bool = false;
+    const CLOCKWISE: bool = false;
+}
+
+/// The y-down convention. North is `(0, -1)`, and angles are measured the same way as
+/// `atan2` measures them on y-down vectors, so they grow clockwise and north is at 270 degrees.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, PartialOrd, Ord, Default, Hash)]
+pub struct YDown;
+
+impl CoordinateSystem for YDown {
+    const Y_DOWN: bool = true;
+    const CLOCKWISE: bool = true;
+}
+
+/// The screen convention. North is `(0, -1)`, as in [YDown], but angles are measured as they
+/// look on screen, so they grow counter-clockwise and north is at 90 degrees, as in [YUp].
+#[derive(Debug, PartialEq, Eq, Clone, Copy, PartialOrd, Ord, Default, Hash)]
+pub struct Screen;
+
+impl CoordinateSystem for Screen {
+    const Y_DOWN: bool = true;
+    const CLOCKWISE: bool = false;
+}
+
+/// Converts an offset given with north up into the offset in `C`.
+pub(crate) fn offset_in<C: CoordinateSystem>((x, y): (i32, i32)) -> (i32, i32) {
+    if C::Y_DOWN {
+        (x, -y)
+    } else {
+        (x, y)
+    }
+}
+
+/// Converts a counter-clockwise angle in degrees into the angle in `C`. This is its own inverse.
+pub(crate) fn angle_in<C: CoordinateSystem>(angle: f32) -> f32 {
+    if C::CLOCKWISE {
+        (360.0 - angle).rem_euclid(360.0)
+    } else {
+        angle
+    }
+}
+
+/// Converts a mathematical angle in degrees, where east is 0 and angles grow counter-clockwise,
+/// into a compass bearing, where north is 0 and bearings grow clockwise. The result is in `0..360`.
+///
+/// This is its own inverse, and [bearing_to_angle] is provided for readability.
+pub fn angle_to_bearing(angle: f32) -> f32 {
+    (90.0 - angle).rem_euclid(360.0)
+}
+
+/// Converts a compass bearing in degrees, where north is 0 and bearings grow clockwise, into
+/// a mathematical angle, where east is 0 and angles grow counter-clockwise. The result is in `0..360`.
+pub fn bearing_to_angle(bearing: f32) -> f32 {
+    angle_to_bearing(bearing)
+}
@@ -0,0 +1,252 @@
+use core::ops;
+
+use crate::{coords, Cardinal, CardinalValues, CoordinateSystem, Ordinal};
+
+/// An enumerator for the four diagonal directions, or the four corners of a square.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Corner {
+    /// NorthEast, or (1, 1)
+    NorthEast,
+    /// NorthWest, or (-1, 1)
+    NorthWest,
+    /// SouthWest, or (-1, -1)
+    SouthWest,
+    /// SouthEast, or (1, -1)
+    SouthEast,
+}
+
+impl Corner {
+    /// Rotates a corner in steps of 90 degrees. Positive amounts rotate counter-clockwise,
+    /// the same as [Cardinal::rotate].
+    #[must_use = "this returns the result of the operation, \
+    without modifying the original"]
+    pub fn rotate(self, amount: i32) -> Self {
+        let mut v = match self {
+            Corner::NorthEast => 0,
+            Corner::NorthWest => 1,
+            Corner::SouthWest => 2,
+            Corner::SouthEast => 3,
+        };
+
+        v += amount;
+        v = v.rem_euclid(4);
+
+        match v {
+            0 => Corner::NorthEast,
+            1 => Corner::NorthWest,
+            2 => Corner::SouthWest,
+            3 => Corner::SouthEast,
+            _ => unreachable!(),
+        }
+    }
+
+    /// Gives an iterator over the four corners
+    pub fn iter_values() -> impl Iterator<Item = Self> {
+        [
+            Corner::NorthEast,
+            Corner::NorthWest,
+            Corner::SouthWest,
+            Corner::SouthEast,
+        ]
+        .into_iter()
+    }
+
+    /// Converts to a simple tuple int form.
+    /// This assumes that north is up.
+    pub fn to_ivec2(self) -> (i32, i32) {
+        match self {
+            Corner::NorthEast => (1, 1),
+            Corner::NorthWest => (-1, 1),
+            Corner::SouthWest => (-1, -1),
+            Corner::SouthEast => (1, -1),
+        }
+    }
+
+    /// Converts to a simple tuple int form, in the given [CoordinateSystem].
+    pub fn to_ivec2_in<C: CoordinateSystem>(self) -> (i32, i32) {
+        coords::offset_in::<C>(self.to_ivec2())
+    }
+
+    /// Splits a corner into the two cardinals on either side of it, in counter-clockwise order,
+    /// so `Corner::NorthEast` splits into `(Cardinal::East, Cardinal::North)`.
+    pub fn split(self) -> (Cardinal, Cardinal) {
+        match self {
+            Corner::NorthEast => (Cardinal::East, Cardinal::North),
+            Corner::NorthWest => (Cardinal::North, Cardinal::West),
+            Corner::SouthWest => (Cardinal::West, Cardinal::South),
+            Corner::SouthEast => (Cardinal::South, Cardinal::East),
+        }
+    }
+
+    /// Combines two cardinals into the corner between them, in either order.
+    ///
+    /// Returns `None` if the two cardinals are equal or opposite.
+    pub fn from_cardinals(a: Cardinal, b: Cardinal) -> Option<Corner> {
+        Ordinal::from_cardinals(a, b).and_then(|v| Corner::try_from(v).ok())
+    }
+}
+
+impl From<Corner> for Ordinal {
+    fn from(corner: Corner) -> Self {
+        match corner {
+            Corner::NorthEast => Ordinal::NorthEast,
+            Corner::NorthWest => Ordinal::NorthWest,
+            Corner::SouthWest => Ordinal::SouthWest,
+            Corner::SouthEast => Ordinal::SouthEast,
+        }
+    }
+}
+
+impl TryFrom<Ordinal> for Corner {
+    /// The cardinal which could not be converted is handed back.
+    type Error = Ordinal;
+
+    fn try_from(ordinal: Ordinal) -> Result<Self, Self::Error> {
+        match ordinal {
+            Ordinal::NorthEast => Ok(Corner::NorthEast),
+            Ordinal::NorthWest => Ok(Corner::NorthWest),
+            Ordinal::SouthWest => Ok(Corner::SouthWest),
+            Ordinal::SouthEast => Ok(Corner::SouthEast),
+            cardinal => Err(cardinal),
+        }
+    }
+}
+
+impl core::fmt::Display for Corner {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        core::fmt::Display::fmt(&Ordinal::from(*self), f)
+    }
+}
+
+/// A struct which a value assigned to each corner. This is the diagonal companion
+/// to [CardinalValues].
+#[derive(Debug, PartialEq, Eq, Clone, Copy, PartialOrd, Ord, Default, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CornerValues<T> {
+    /// The value assigned to the north east.
+    pub north_east: T,
+    /// The value assigned to the north west.
+    pub north_west: T,
+    /// The value assigned to the south west.
+    pub south_west: T,
+    /// The value assigned to the south east.
+    pub south_east: T,
+}
+
+impl<T> CornerValues<T> {
+    /// Converts a [CornerValues] from one type to another.
+    pub fn map<B, F>(self, mut f: F) -> CornerValues<B>
+    where
+        F: FnMut(T) -> B,
+    {
+        CornerValues {
+            north_east: f(self.north_east),
+            north_west: f(self.north_west),
+            south_west: f(self.south_west),
+            south_east: f(self.south_east),
+        }
+    }
+
+    /// Builds each corner by combining the two sides next to it. The sides are given
+    /// in counter-clockwise order, so the north east corner is `f(&east, &north)`.
+    pub fn from_sides<S, F>(sides: &CardinalValues<S>, mut f: F) -> Self
+    where
+        F: FnMut(&S, &S) -> T,
+    {
+        CornerValues {
+            north_east: f(&sides.east, &sides.north),
+            north_west: f(&sides.north, &sides.west),
+            south_west: f(&sides.west, &sides.south),
+            south_east: f(&sides.south, &sides.east),
+        }
+    }
+}
+
+impl<T> CardinalValues<T> {
+    /// Builds each side by combining the two corners next to it. The corners are given
+    /// in counter-clockwise order, so the east side is `f(&south_east, &north_east)`.
+    pub fn from_corners<C, F>(corners: &CornerValues<C>, mut f: F) -> Self
+    where
+        F: FnMut(&C, &C) -> T,
+    {
+        CardinalValues {
+            east: f(&corners.south_east, &corners.north_east),
+            north: f(&corners.north_east, &corners.north_west),
+            west: f(&corners.north_west, &corners.south_west),
+            south: f(&corners.south_west, &corners.south_east),
+        }
+    }
+}
+
+impl<T> ops::Index<Corner> for CornerValues<T> {
+    type Output = T;
+
+    fn index(&self, index: Corner) -> &Self::Output {
+        match index {
+            Corner::NorthEast => &self.north_east,
+            Corner::NorthWest => &self.north_west,
+            Corner::SouthWest => &self.south_west,
+            Corner::SouthEast => &self.south_east,
+        }
+    }
+}
+
+impl<T: Copy> IntoIterator for CornerValues<T> {
+    type Item = T;
+
+    type IntoIter = CornerIterator<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        CornerIterator(self, 0)
+    }
+}
+
+/// An iterator over a CornerValues, in the same order as [Corner::iter_values].
+pub struct CornerIterator<T>(CornerValues<T>, usize);
+
+impl<T> CornerIterator<T> {
+    /// Converts this iterator into an Enumerated one, where each value has its Corner given.
+    pub fn enumerate(self) -> CornerEnumeratedIterator<T> {
+        CornerEnumeratedIterator(self.0, self.1)
+    }
+}
+
+impl<T: Copy> Iterator for CornerIterator<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let found = match self.1 {
+            0 => Some(self.0.north_east),
+            1 => Some(self.0.north_west),
+            2 => Some(self.0.south_west),
+            3 => Some(self.0.south_east),
+            _ => return None,
+        };
+
+        self.1 += 1;
+
+        found
+    }
+}
+
+/// An enumerated iterator for [CornerValues]. This should be constructed with the `enumerate` method
+/// on [CornerIterator].
+pub struct CornerEnumeratedIterator<T>(CornerValues<T>, usize);
+impl<T: Copy> Iterator for CornerEnumeratedIterator<T> {
+    type Item = (Corner, T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let found = match self.1 {
+            0 => Some((Corner::NorthEast, self.0.north_east)),
+            1 => Some((Corner::NorthWest, self.0.north_west)),
+            2 => Some((Corner::SouthWest, self.0.south_west)),
+            3 => Some((Corner::SouthEast, self.0.south_east)),
+            _ => return None,
+        };
+
+        self.1 += 1;
+
+        found
+    }
+}
@@ -0,0 +1,221 @@
+use crate::{coords, Cardinal, CardinalValues, CoordinateSystem};
+
+/// An element of the dihedral group D4: one of the four rotations or four reflections
+/// of a square. These are the ways a tile can be turned and mirrored.
+///
+/// Rotations are counter-clockwise, matching [Cardinal::rotate].
+#[derive(Debug, PartialEq, Eq, Clone, Copy, PartialOrd, Ord, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "RawD4"))]
+pub struct D4 {
+    // the transform is `c -> (flipped ? -c : c) + offset`, with cardinals counted
+    // counter-clockwise from east.
+    flipped: bool,
+    offset: u8,
+}
+
+impl D4 {
+    /// Leaves everything where it is.
+    pub const IDENTITY: D4 = D4::new(false, 0);
+    /// Rotates a quarter turn counter-clockwise, so east goes to north.
+    pub const ROTATE_90: D4 = D4::new(false, 1);
+    /// Rotates a half turn, so east goes to west.
+    pub const ROTATE_180: D4 = D4::new(false, 2);
+    /// Rotates a quarter turn clockwise, so east goes to south.
+    pub const ROTATE_270: D4 = D4::new(false, 3);
+    /// Mirrors across the east-west axis, swapping north and south.
+    pub const FLIP_VERTICAL: D4 = D4::new(true, 0);
+    /// Mirrors across the diagonal running from south west to north east, swapping
+    /// east with north and west with south.
+    pub const FLIP_DIAGONAL: D4 = D4::new(true, 1);
+    /// Mirrors across the north-south axis, swapping east and west.
+    pub const FLIP_HORIZONTAL: D4 = D4::new(true, 2);
+    /// Mirrors across the diagonal running from north west to south east, swapping
+    /// east with south and west with north.
+    pub const FLIP_ANTI_DIAGONAL: D4 = D4::new(true, 3);
+
+    const fn new(flipped: bool, offset: u8) -> Self {
+        Self { flipped, offset }
+    }
+
+    /// Gives an iterator over all eight elements: the four rotations, and then the four reflections.
+    pub fn iter_values() -> impl Iterator<Item = Self> {
+        [
+            D4::IDENTITY,
+            D4::ROTATE_90,
+            D4::ROTATE_180,
+            D4::ROTATE_270,
+            D4::FLIP_VERTICAL,
+            D4::FLIP_DIAGONAL,
+            D4::FLIP_HORIZONTAL,
+            D4::FLIP_ANTI_DIAGONAL,
+        ]
+        .into_iter()
+    }
+
+    /// A rotation by `amount` quarter turns, with the same meaning as in [Cardinal::rotate].
+    pub fn rotation(amount: i32) -> Self {
+        Self::new(false, amount.rem_euclid(4) as u8)
+    }
+
+    /// Is one of the four reflections rather than a rotation.
+    pub fn is_reflection(self) -> bool {
+        self.flipped
+    }
+
+    /// Composes two transforms into one which applies `self` first and `other` second.
+    #[must_use = "this returns the result of the operation, \
+    without modifying the original"]
+    pub fn then(self, other: D4) -> D4 {
+        let offset = if other.flipped {
+            4 - self.offset
+        } else {
+            self.offset
+        };
+
+        Self::new(self.flipped ^ other.flipped, (offset + other.offset) % 4)
+    }
+
+    /// Returns the transform which undoes this one.
+    #[must_use = "this returns the result of the operation, \
+    without modifying the original"]
+    pub fn inverse(self) -> D4 {
+        if self.flipped {
+            self
+        } else {
+            Self::new(false, (4 - self.offset) % 4)
+        }
+    }
+
+    /// Applies this transform to a cardinal.
+    pub fn apply_cardinal(self, cardinal: Cardinal) -> Cardinal {
+        let cardinal = if self.flipped {
+            match cardinal {
+                Cardinal::North => Cardinal::South,
+                Cardinal::South => Cardinal::North,
+                other => other,
+            }
+        } else {
+            cardinal
+        };
+
+        cardinal.rotate(self.offset as i32)
+    }
+
+    /// Applies this transform to an offset in the tuple int form of [Cardinal::to_ivec2].
+    /// This assumes that north is up.
+    pub fn apply_offset(self, offset: (i32, i32)) -> (i32, i32) {
+        let (mut x, mut y) = offset;
+        if self.flipped {
+            y = -y;
+        }
+
+        for _ in 0..self.offset {
+            (x, y) = (-y, x);
+        }
+
+        (x, y)
+    }
+
+    /// Applies this transform to an offset in the tuple int form of [Cardinal::to_ivec2_in].
+    pub fn apply_offset_in<C: CoordinateSystem>(self, offset: (i32, i32)) -> (i32, i32) {
+        coords::offset_in::<C>(self.apply_offset(coords::offset_in::<C>(offset)))
+    }
+
+    /// Applies this transform to a [CardinalValues] by moving each value to the side its
+    /// cardinal is sent to, so `result[d4.apply_cardinal(c)] == values[c]`.
+    pub fn apply_values<T>(self, values: CardinalValues<T>) -> CardinalValues<T> {
+        let mut array = [values.east, values.north, values.west, values.south];
+        if self.flipped {
+            array.swap(1, 3);
+        }
+        array.rotate_right(self.offset as usize);
+
+        let [east, north, west, south] = array;
+        CardinalValues {
+            east,
+            north,
+            west,
+            south,
+        }
+    }
+}
+
+// the serialized form of a [D4], which is checked before it becomes one, since an offset
+// of four or more would break the arithmetic above.
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct RawD4 {
+    flipped: bool,
+    offset: u8,
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<RawD4> for D4 {
+    type Error = String;
+
+    fn try_from(raw: RawD4) -> Result<Self, Self::Error> {
+        if raw.offset < 4 {
+            Ok(D4::new(raw.flipped, raw.offset))
+        } else {
+            Err(format!("D4 offset must be below 4, but was {}", raw.offset))
+        }
+    }
+}
+
+impl<T> CardinalValues<T> {
+    /// Rotates the values by `amount` quarter turns, moving each one the same way
+    /// [Cardinal::rotate] moves its cardinal, so positive amounts turn counter-clockwise.
+    ///
+    /// ```
+    /// # use cardinal_values::{Cardinal, CardinalValues};
+    /// let doors = CardinalValues { east: 1, north: 2, west: 3, south: 4 };
+    /// for amount in -4..=4 {
+    ///     let rotated = doors.rotate(amount);
+    ///     for c in Cardinal::iter_values() {
+    ///         assert_eq!(rotated[c.rotate(amount)], doors[c]);
+    ///     }
+    /// }
+    /// ```
+    #[must_use = "this returns the result of the operation, \
+    without modifying the original"]
+    pub fn rotate(self, amount: i32) -> Self {
+        D4::rotation(amount).apply_values(self)
+    }
+
+    /// Mirrors the values across the north-south axis, swapping east and west.
+    ///
+    /// Like the other flips, this moves each value the same way [D4::apply_cardinal] moves
+    /// its cardinal, with [D4::FLIP_HORIZONTAL].
+    ///
+    /// ```
+    /// # use cardinal_values::{Cardinal, CardinalValues, D4};
+    /// let doors = CardinalValues { east: 1, north: 2, west: 3, south: 4 };
+    /// let flipped = doors.flip_horizontal();
+    /// for c in Cardinal::iter_values() {
+    ///     assert_eq!(flipped[D4::FLIP_HORIZONTAL.apply_cardinal(c)], doors[c]);
+    /// }
+    /// ```
+    #[must_use = "this returns the result of the operation, \
+    without modifying the original"]
+    pub fn flip_horizontal(self) -> Self {
+        D4::FLIP_HORIZONTAL.apply_values(self)
+    }
+
+    /// Mirrors the values across the east-west axis, swapping north and south. This is
+    /// [D4::FLIP_VERTICAL].
+    #[must_use = "this returns the result of the operation, \
+    without modifying the original"]
+    pub fn flip_vertical(self) -> Self {
+        D4::FLIP_VERTICAL.apply_values(self)
+    }
+
+    /// Mirrors the values across the diagonal running from south west to north east,
+    /// swapping east with north and west with south, as a transpose does. This is
+    /// [D4::FLIP_DIAGONAL].
+    #[must_use = "this returns the result of the operation, \
+    without modifying the original"]
+    pub fn flip_diagonal(self) -> Self {
+        D4::FLIP_DIAGONAL.apply_values(self)
+    }
+}
@@ -0,0 +1,185 @@
+//! Conversions between the tile flip flags of map editors and [D4] transforms.
+//!
+//! Once a tile's flags are turned into a [D4], [D4::apply_cardinal] and [D4::apply_values]
+//! orient its per-side data, such as collisions and connections, to match how it's drawn.
+
+use crate::D4;
+
+/// The flip flags Tiled stores in the high bits of a tile's global id.
+///
+/// Tiled applies the diagonal flip first, swapping the x and y axes, then the horizontal
+/// flip, then the vertical flip.
+///
+/// ```
+/// # use cardinal_values::{Cardinal, D4};
+/// # use cardinal_values::flip::TiledFlip;
+/// // Tiled's "rotate 90 degrees clockwise" sets the diagonal and horizontal flags.
+/// let (flip, id) = TiledFlip::from_gid(TiledFlip::DIAGONAL_BIT | TiledFlip::HORIZONTAL_BIT | 7);
+/// assert_eq!(id, 7);
+/// assert_eq!(D4::from(flip), D4::ROTATE_270);
+/// assert_eq!(D4::from(flip).apply_cardinal(Cardinal::North), Cardinal::East);
+///
+/// // and diagonal with vertical is counter-clockwise.
+/// let flip = TiledFlip { horizontal: false, vertical: true, diagonal: true };
+/// assert_eq!(D4::from(flip), D4::ROTATE_90);
+///
+/// // every combination of flags is a different transform, so they round-trip.
+/// for bits in 0..8 {
+///     let flip = TiledFlip {
+///         horizontal: bits & 1 != 0,
+///         vertical: bits & 2 != 0,
+///         diagonal: bits & 4 != 0,
+///     };
+///     assert_eq!(TiledFlip::from(D4::from(flip)), flip);
+///     assert_eq!(TiledFlip::from_gid(flip.to_gid(7)), (flip, 7));
+/// }
+/// for d4 in D4::iter_values() {
+///     assert_eq!(D4::from(TiledFlip::from(d4)), d4);
+/// }
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone, Copy, PartialOrd, Ord, Default, Hash)]
+pub struct TiledFlip {
+    /// Mirrored left to right.
+    pub horizontal: bool,
+    /// Mirrored top to bottom.
+    pub vertical: bool,
+    /// Mirrored across the diagonal from the top left to the bottom right.
+    pub diagonal: bool,
+}
+
+impl TiledFlip {
+    /// The bit of a global id which marks a horizontal flip.
+    pub const HORIZONTAL_BIT: u32 = 0x8000_0000;
+    /// The bit of a global id which marks a vertical flip.
+    pub const VERTICAL_BIT: u32 = 0x4000_0000;
+    /// The bit of a global id which marks a diagonal flip.
+    pub const DIAGONAL_BIT: u32 = 0x2000_0000;
+    /// The bit of a global id which Tiled uses to rotate hexagonal tiles. It has no meaning
+    /// for square tiles, but is still cleared from the id.
+    pub const HEX_ROTATION_BIT: u32 = 0x1000_0000;
+
+    /// Splits a global id into its flip flags and the id with every flag bit cleared.
+    pub fn from_gid(gid: u32) -> (Self, u32) {
+        let flags = Self {
+            horizontal: gid & Self::HORIZONTAL_BIT != 0,
+            vertical: gid & Self::VERTICAL_BIT != 0,
+            diagonal: gid & Self::DIAGONAL_BIT != 0,
+        };
+        let mask =
+            Self::HORIZONTAL_BIT | Self::VERTICAL_BIT | Self::DIAGONAL_BIT | Self::HEX_ROTATION_BIT;
+
+        (flags, gid & !mask)
+    }
+
+    /// Sets these flip flags on an id.
+    pub fn to_gid(self, id: u32) -> u32 {
+        let mut gid = id;
+        if self.horizontal {
+            gid |= Self::HORIZONTAL_BIT;
+        }
+        if self.vertical {
+            gid |= Self::VERTICAL_BIT;
+        }
+        if self.diagonal {
+            gid |= Self::DIAGONAL_BIT;
+        }
+
+        gid
+    }
+
+    fn iter_values() -> impl Iterator<Item = Self> {
+        (0..8).map(|bits| Self {
+            horizontal: bits & 1 != 0,
+            vertical: bits & 2 != 0,
+            diagonal: bits & 4 != 0,
+        })
+    }
+}
+
+impl From<TiledFlip> for D4 {
+    fn from(flip: TiledFlip) -> Self {
+        let mut d4 = D4::IDENTITY;
+        // Tiled is y-down, so swapping its x and y axes swaps east with south.
+        if flip.diagonal {
+            d4 = d4.then(D4::FLIP_ANTI_DIAGONAL);
+        }
+        if flip.horizontal {
+            d4 = d4.then(D4::FLIP_HORIZONTAL);
+        }
+        if flip.vertical {
+            d4 = d4.then(D4::FLIP_VERTICAL);
+        }
+
+        d4
+    }
+}
+
+impl From<D4> for TiledFlip {
+    fn from(d4: D4) -> Self {
+        TiledFlip::iter_values()
+            .find(|v| D4::from(*v) == d4)
+            .expect("the eight flag combinations cover every element of D4")
+    }
+}
+
+/// The flip flags LDtk stores in the `f` field of a tile, where bit 0 is an x flip and
+/// bit 1 is a y flip.
+///
+/// ```
+/// # use cardinal_values::{Cardinal, D4};
+/// # use cardinal_values::flip::LdtkFlip;
+/// let flip = LdtkFlip::from_bits(1);
+/// assert_eq!(D4::from(flip).apply_cardinal(Cardinal::East), Cardinal::West);
+/// assert_eq!(D4::from(LdtkFlip::from_bits(3)), D4::ROTATE_180);
+/// for bits in 0..4 {
+///     let flip = LdtkFlip::from_bits(bits);
+///     assert_eq!(LdtkFlip::try_from(D4::from(flip)), Ok(flip));
+/// }
+/// assert_eq!(LdtkFlip::try_from(D4::ROTATE_90), Err(D4::ROTATE_90));
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone, Copy, PartialOrd, Ord, Default, Hash)]
+pub struct LdtkFlip {
+    /// Mirrored left to right.
+    pub x: bool,
+    /// Mirrored top to bottom.
+    pub y: bool,
+}
+
+impl LdtkFlip {
+    /// Reads the flags from the `f` field of a tile. Bits above the lowest two are ignored.
+    pub fn from_bits(bits: u8) -> Self {
+        Self {
+            x: bits & 1 != 0,
+            y: bits & 2 != 0,
+        }
+    }
+
+    /// Returns the flags as the `f` field of a tile.
+    pub fn bits(self) -> u8 {
+        self.x as u8 | (self.y as u8) << 1
+    }
+}
+
+impl From<LdtkFlip> for D4 {
+    fn from(flip: LdtkFlip) -> Self {
+        match (flip.x, flip.y) {
+            (false, false) => D4::IDENTITY,
+            (true, false) => D4::FLIP_HORIZONTAL,
+            (false, true) => D4::FLIP_VERTICAL,
+            (true, true) => D4::ROTATE_180,
+        }
+    }
+}
+
+impl TryFrom<D4> for LdtkFlip {
+    /// LDtk can't rotate tiles by a quarter turn, or mirror them across a diagonal,
+    /// so those transforms are handed back.
+    type Error = D4;
+
+    fn try_from(d4: D4) -> Result<Self, Self::Error> {
+        (0..4)
+            .map(LdtkFlip::from_bits)
+            .find(|v| D4::from(*v) == d4)
+            .ok_or(d4)
+    }
+}
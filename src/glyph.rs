@@ -0,0 +1,154 @@
+use crate::Cardinal;
+
+/// The ways a [Cardinal] can be written, for use with [Cardinal::display].
+#[derive(Debug, PartialEq, Eq, Clone, Copy, PartialOrd, Ord, Default, Hash)]
+pub enum CardinalFormat {
+    /// A lowercase word, such as `north`. This is what `Display` writes.
+    #[default]
+    Word,
+    /// A capitalized word, such as `North`.
+    Capitalized,
+    /// A single uppercase letter, such as `N`.
+    Letter,
+    /// An ASCII arrow, one of `>`, `^`, `<` and `v`.
+    AsciiArrow,
+    /// A Unicode arrow, one of `→`, `↑`, `←` and `↓`.
+    UnicodeArrow,
+}
+
+/// A [Cardinal] paired with the [CardinalFormat] to write it in. This should be constructed
+/// with [Cardinal::display].
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct DisplayCardinal {
+    cardinal: Cardinal,
+    format: CardinalFormat,
+}
+
+impl Cardinal {
+    /// Returns a wrapper which writes this cardinal in the given format with `Display`.
+    pub fn display(self, format: CardinalFormat) -> DisplayCardinal {
+        DisplayCardinal {
+            cardinal: self,
+            format,
+        }
+    }
+
+    /// Reads a WASD key, where `w` is north. Returns `None` for any other key.
+    pub fn from_wasd(key: char) -> Option<Cardinal> {
+        match key.to_ascii_lowercase() {
+            'd' => Some(Cardinal::East),
+            'w' => Some(Cardinal::North),
+            'a' => Some(Cardinal::West),
+            's' => Some(Cardinal::South),
+            _ => None,
+        }
+    }
+
+    /// Reads a vi movement key, where `k` is north. Returns `None` for any other key.
+    pub fn from_vi_key(key: char) -> Option<Cardinal> {
+        match key.to_ascii_lowercase() {
+            'l' => Some(Cardinal::East),
+            'k' => Some(Cardinal::North),
+            'h' => Some(Cardinal::West),
+            'j' => Some(Cardinal::South),
+            _ => None,
+        }
+    }
+}
+
+impl core::fmt::Display for DisplayCardinal {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let text = match (self.format, self.cardinal) {
+            (CardinalFormat::Word, cardinal) => return core::fmt::Display::fmt(&cardinal, f),
+            (CardinalFormat::Capitalized, Cardinal::East) => "East",
+            (CardinalFormat::Capitalized, Cardinal::North) => "North",
+            (CardinalFormat::Capitalized, Cardinal::West) => "West",
+            (CardinalFormat::Capitalized, Cardinal::South) => "South",
+            (CardinalFormat::Letter, Cardinal::East) => "E",
+            (CardinalFormat::Letter, Cardinal::North) => "N",
+            (CardinalFormat::Letter, Cardinal::West) => "W",
+            (CardinalFormat::Letter, Cardinal::South) => "S",
+            (CardinalFormat::AsciiArrow, Cardinal::East) => ">",
+            (CardinalFormat::AsciiArrow, Cardinal::North) => "^",
+            (CardinalFormat::AsciiArrow, Cardinal::West) => "<",
+            (CardinalFormat::AsciiArrow, Cardinal::South) => "v",
+            (CardinalFormat::UnicodeArrow, Cardinal::East) => "→",
+            (CardinalFormat::UnicodeArrow, Cardinal::North) => "↑",
+            (CardinalFormat::UnicodeArrow, Cardinal::West) => "←",
+            (CardinalFormat::UnicodeArrow, Cardinal::South) => "↓",
+        };
+
+        f.pad(text)
+    }
+}
+
+impl TryFrom<char> for Cardinal {
+    type Error = ParseCardinalError;
+
+    /// Reads a letter (`N`, `E`, `S` or `W`, in either case), an ASCII arrow (`^`, `>`, `v`
+    /// or `<`) or a Unicode arrow (`↑`, `→`, `↓` or `←`).
+    ///
+    /// WASD and vi keys clash with the letters, since `w` could be west or north, so they
+    /// are read with [Cardinal::from_wasd] and [Cardinal::from_vi_key] instead.
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        match c {
+            'e' | 'E' | '>' | '→' => Ok(Cardinal::East),
+            'n' | 'N' | '^' | '↑' => Ok(Cardinal::North),
+            'w' | 'W' | '<' | '←' => Ok(Cardinal::West),
+            's' | 'S' | 'v' | 'V' | '↓' => Ok(Cardinal::South),
+            _ => Err(ParseCardinalError {
+                input: c.to_string(),
+            }),
+        }
+    }
+}
+
+impl core::str::FromStr for Cardinal {
+    type Err = ParseCardinalError;
+
+    /// Reads a word, such as `north`, in any case, or any single character accepted by
+    /// `TryFrom<char>`. Surrounding whitespace is ignored.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let trimmed = s.trim();
+        let mut chars = trimmed.chars();
+
+        let found = match (chars.next(), chars.next()) {
+            (Some(c), None) => Cardinal::try_from(c).ok(),
+            _ => Cardinal::iter_values().find(|v| {
+                v.display(CardinalFormat::Word)
+                    .to_string()
+                    .eq_ignore_ascii_case(trimmed)
+            }),
+        };
+
+        found.ok_or_else(|| ParseCardinalError {
+            input: s.to_string(),
+        })
+    }
+}
+
+/// The error returned when a string or character can't be read as a [Cardinal].
+#[derive(Debug, PartialEq, Eq, Clone, Hash)]
+pub struct ParseCardinalError {
+    input: String,
+}
+
+impl ParseCardinalError {
+    /// The input which couldn't be read.
+    pub fn input(&self) -> &str {
+        &self.input
+    }
+}
+
+impl core::fmt::Display for ParseCardinalError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "{:?} is not a cardinal; expected a word such as \"north\", a letter such as 'N', \
+            or an arrow such as '^' or '↑'",
+            self.input
+        )
+    }
+}
+
+impl std::error::Error for ParseCardinalError {}
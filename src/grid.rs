@@ -0,0 +1,546 @@
+use core::marker::PhantomData;
+
+use crate::{Cardinal, CardinalValues, CoordinateSystem, YUp};
+
+/// A position on an integer grid. Steps follow [Cardinal::to_ivec2], so north is +y,
+/// unless another [CoordinateSystem] is given to one of the `_in` variants.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, PartialOrd, Ord, Default, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GridPos {
+    /// The x coordinate, which grows towards the east.
+    pub x: i32,
+    /// The y coordinate, which grows towards the north in [YUp].
+    pub y: i32,
+}
+
+impl GridPos {
+    /// Creates a new position.
+    pub const fn new(x: i32, y: i32) -> Self {
+        Self { x, y }
+    }
+
+    /// Returns the position one step away in the given direction.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the step goes past `i32::MIN` or `i32::MAX`. Use [step_bounded] to
+    /// stay inside a grid instead.
+    ///
+    /// [step_bounded]: GridPos::step_bounded
+    #[must_use = "this returns the result of the operation, \
+    without modifying the original"]
+    pub fn step(self, cardinal: Cardinal) -> Self {
+        self.step_in::<YUp>(cardinal)
+    }
+
+    /// Returns the position one step away in the given direction, in the given [CoordinateSystem].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the step goes past `i32::MIN` or `i32::MAX`.
+    #[must_use = "this returns the result of the operation, \
+    without modifying the original"]
+    pub fn step_in<C: CoordinateSystem>(self, cardinal: Cardinal) -> Self {
+        self.step_n_in::<C>(cardinal, 1)
+    }
+
+    /// Returns the position `n` steps away in the given direction.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the steps go past `i32::MIN` or `i32::MAX`.
+    #[must_use = "this returns the result of the operation, \
+    without modifying the original"]
+    pub fn step_n(self, cardinal: Cardinal, n: i32) -> Self {
+        self.step_n_in::<YUp>(cardinal, n)
+    }
+
+    /// Returns the position `n` steps away in the given direction, in the given [CoordinateSystem].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the steps go past `i32::MIN` or `i32::MAX`.
+    #[must_use = "this returns the result of the operation, \
+    without modifying the original"]
+    pub fn step_n_in<C: CoordinateSystem>(self, cardinal: Cardinal, n: i32) -> Self {
+        self.checked_step_n_in::<C>(cardinal, n)
+            .expect("stepped past the range of an `i32`")
+    }
+
+    fn checked_step_n_in<C: CoordinateSystem>(self, cardinal: Cardinal, n: i32) -> Option<Self> {
+        // an offset is always -1, 0 or 1, so only `n = i32::MIN` can overflow the multiply.
+        let (x, y) = cardinal.to_ivec2_in::<C>();
+
+        Some(Self::new(
+            self.x.checked_add(x.checked_mul(n)?)?,
+            self.y.checked_add(y.checked_mul(n)?)?,
+        ))
+    }
+
+    /// Returns the four positions one step away.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a step goes past `i32::MIN` or `i32::MAX`.
+    pub fn neighbors(self) -> CardinalValues<GridPos> {
+        self.neighbors_in::<YUp>()
+    }
+
+    /// Returns the four positions one step away, in the given [CoordinateSystem].
+    ///
+    /// # Panics
+    ///
+    /// Panics if a step goes past `i32::MIN` or `i32::MAX`.
+    pub fn neighbors_in<C: CoordinateSystem>(self) -> CardinalValues<GridPos> {
+        CardinalValues {
+            east: self.step_in::<C>(Cardinal::East),
+            north: self.step_in::<C>(Cardinal::North),
+            west: self.step_in::<C>(Cardinal::West),
+            south: self.step_in::<C>(Cardinal::South),
+        }
+    }
+
+    /// Is inside a grid of the given size, with its origin at `(0, 0)`.
+    pub fn in_bounds(self, width: u32, height: u32) -> bool {
+        (0..width as i64).contains(&(self.x as i64))
+            && (0..height as i64).contains(&(self.y as i64))
+    }
+
+    /// Returns the position one step away, or `None` if that leaves a grid of the given size.
+    pub fn step_bounded(self, cardinal: Cardinal, width: u32, height: u32) -> Option<Self> {
+        self.step_bounded_in::<YUp>(cardinal, width, height)
+    }
+
+    /// Returns the position one step away, or `None` if that leaves a grid of the given size,
+    /// in the given [CoordinateSystem].
+    pub fn step_bounded_in<C: CoordinateSystem>(
+        self,
+        cardinal: Cardinal,
+        width: u32,
+        height: u32,
+    ) -> Option<Self> {
+        let next = self.checked_step_n_in::<C>(cardinal, 1)?;
+
+        next.in_bounds(width, height).then_some(next)
+    }
+
+    /// Returns the position one step away, wrapping around the edges of a grid of the given size.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `width` or `height` is zero, or if the wrapped position is past `i32::MAX`,
+    /// which can only happen when the size is larger than that.
+    pub fn step_wrapping(self, cardinal: Cardinal, width: u32, height: u32) -> Self {
+        self.step_wrapping_in::<YUp>(cardinal, width, height)
+    }
+
+    /// Returns the position one step away, wrapping around the edges of a grid of the given size,
+    /// in the given [CoordinateSystem].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `width` or `height` is zero, or if the wrapped position is past `i32::MAX`,
+    /// which can only happen when the size is larger than that.
+    pub fn step_wrapping_in<C: CoordinateSystem>(
+        self,
+        cardinal: Cardinal,
+        width: u32,
+        height: u32,
+    ) -> Self {
+        // stepping in `i64` can't overflow, and keeps sizes above `i32::MAX` positive.
+        let (x, y) = cardinal.to_ivec2_in::<C>();
+        let wrap = |v: i32, step: i32, size: u32| {
+            let wrapped = (v as i64 + step as i64).rem_euclid(size as i64);
+
+            i32::try_from(wrapped).expect("wrapped past the range of an `i32`")
+        };
+
+        Self::new(wrap(self.x, x, width), wrap(self.y, y, height))
+    }
+
+    /// Returns the four positions one step away, with `None` for any which would leave
+    /// a grid of the given size.
+    pub fn neighbors_bounded(self, width: u32, height: u32) -> CardinalValues<Option<GridPos>> {
+        self.neighbors_bounded_in::<YUp>(width, height)
+    }
+
+    /// Returns the four positions one step away, with `None` for any which would leave
+    /// a grid of the given size, in the given [CoordinateSystem].
+    pub fn neighbors_bounded_in<C: CoordinateSystem>(
+        self,
+        width: u32,
+        height: u32,
+    ) -> CardinalValues<Option<GridPos>> {
+        CardinalValues {
+            east: self.step_bounded_in::<C>(Cardinal::East, width, height),
+            north: self.step_bounded_in::<C>(Cardinal::North, width, height),
+            west: self.step_bounded_in::<C>(Cardinal::West, width, height),
+            south: self.step_bounded_in::<C>(Cardinal::South, width, height),
+        }
+    }
+
+    /// Returns the four positions one step away, wrapping around the edges of a grid
+    /// of the given size.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `width` or `height` is zero, or if a wrapped position is past `i32::MAX`,
+    /// which can only happen when the size is larger than that.
+    pub fn neighbors_wrapping(self, width: u32, height: u32) -> CardinalValues<GridPos> {
+        self.neighbors_wrapping_in::<YUp>(width, height)
+    }
+
+    /// Returns the four positions one step away, wrapping around the edges of a grid
+    /// of the given size, in the given [CoordinateSystem].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `width` or `height` is zero, or if a wrapped position is past `i32::MAX`,
+    /// which can only happen when the size is larger than that.
+    pub fn neighbors_wrapping_in<C: CoordinateSystem>(
+        self,
+        width: u32,
+        height: u32,
+    ) -> CardinalValues<GridPos> {
+        CardinalValues {
+            east: self.step_wrapping_in::<C>(Cardinal::East, width, height),
+            north: self.step_wrapping_in::<C>(Cardinal::North, width, height),
+            west: self.step_wrapping_in::<C>(Cardinal::West, width, height),
+            south: self.step_wrapping_in::<C>(Cardinal::South, width, height),
+        }
+    }
+}
+
+impl From<(i32, i32)> for GridPos {
+    fn from((x, y): (i32, i32)) -> Self {
+        Self::new(x, y)
+    }
+}
+
+impl From<GridPos> for (i32, i32) {
+    fn from(pos: GridPos) -> Self {
+        (pos.x, pos.y)
+    }
+}
+
+/// A dense, row-major grid of values, indexed by [GridPos].
+///
+/// Directions follow the [CoordinateSystem] `C`. In [YUp], the default, `(0, 0)` is the
+/// south west corner and the first row stored is the southern one. In a y-down system,
+/// such as [YDown], `(0, 0)` is the north west corner and the first row stored is the
+/// northern one.
+///
+/// Every cell has a [GridPos], so neither side of a grid can be longer than `i32::MAX`.
+///
+/// [YDown]: crate::YDown
+#[derive(Debug, PartialEq, Eq, Clone, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(
+        try_from = "RawGrid<T>",
+        bound(deserialize = "T: serde::Deserialize<'de>")
+    )
+)]
+pub struct Grid<T, C = YUp> {
+    width: u32,
+    height: u32,
+    cells: Vec<T>,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    coordinates: PhantomData<C>,
+}
+
+// the serialized form of a [Grid], which is checked before it becomes one, so the cells
+// always match the size.
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct RawGrid<T> {
+    width: u32,
+    height: u32,
+    cells: Vec<T>,
+}
+
+#[cfg(feature = "serde")]
+impl<T, C> TryFrom<RawGrid<T>> for Grid<T, C> {
+    type Error = String;
+
+    fn try_from(raw: RawGrid<T>) -> Result<Self, Self::Error> {
+        let len = raw.cells.len();
+
+        Self::from_cells(raw.width, raw.height, raw.cells).ok_or_else(|| {
+            format!(
+                "a {}x{} grid can't hold {} cells",
+                raw.width, raw.height, len
+            )
+        })
+    }
+}
+
+impl<T> Grid<T> {
+    /// Creates a grid with every cell set to `value`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `width` or `height` is larger than `i32::MAX`, or the grid has more
+    /// cells than fit in a `usize`.
+    pub fn new(width: u32, height: u32, value: T) -> Self
+    where
+        T: Clone,
+    {
+        let len = Self::cell_count(width, height).expect("grid is too large");
+
+        Self {
+            width,
+            height,
+            cells: vec![value; len],
+            coordinates: PhantomData,
+        }
+    }
+
+    /// Creates a grid by calling `f` with the position of each cell.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `width` or `height` is larger than `i32::MAX`, or the grid has more
+    /// cells than fit in a `usize`.
+    pub fn from_fn<F>(width: u32, height: u32, mut f: F) -> Self
+    where
+        F: FnMut(GridPos) -> T,
+    {
+        let len = Self::cell_count(width, height).expect("grid is too large");
+        let mut cells = Vec::with_capacity(len);
+        for y in 0..height {
+            // both sides are at most `i32::MAX`, so these casts can't wrap.
+            cells.extend((0..width).map(|x| f(GridPos::new(x as i32, y as i32))));
+        }
+
+        Self {
+            width,
+            height,
+            cells,
+            coordinates: PhantomData,
+        }
+    }
+
+    /// Creates a grid from cells in row-major order, starting with the row at `y = 0`.
+    /// Returns `None` if there aren't exactly `width * height` cells, or if `width` or
+    /// `height` is larger than `i32::MAX`.
+    pub fn from_vec(width: u32, height: u32, cells: Vec<T>) -> Option<Self> {
+        Self::from_cells(width, height, cells)
+    }
+}
+
+impl<T, C> Grid<T, C> {
+    fn cell_count(width: u32, height: u32) -> Option<usize> {
+        let max = i32::MAX as u32;
+        if width > max || height > max {
+            return None;
+        }
+
+        usize::try_from(width)
+            .ok()?
+            .checked_mul(usize::try_from(height).ok()?)
+    }
+
+    fn from_cells(width: u32, height: u32, cells: Vec<T>) -> Option<Self> {
+        (Self::cell_count(width, height)? == cells.len()).then_some(Self {
+            width,
+            height,
+            cells,
+            coordinates: PhantomData,
+        })
+    }
+}
+
+impl<T, C: CoordinateSystem> Grid<T, C> {
+    /// Reinterprets the grid in another [CoordinateSystem]. The cells keep their positions,
+    /// so in a y-down system the first row stored becomes the northern one.
+    pub fn with_coordinate_system<D: CoordinateSystem>(self) -> Grid<T, D> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells,
+            coordinates: PhantomData,
+        }
+    }
+
+    /// Returns the cells in row-major order, starting with the row at `y = 0`.
+    pub fn into_vec(self) -> Vec<T> {
+        self.cells
+    }
+
+    /// The number of columns.
+    pub fn width(&self) -> u32 {
+        self.width
+    }
+
+    /// The number of rows.
+    pub fn height(&self) -> u32 {
+        self.height
+    }
+
+    /// Is the position inside the grid.
+    pub fn contains(&self, pos: GridPos) -> bool {
+        pos.in_bounds(self.width, self.height)
+    }
+
+    fn index_of(&self, pos: GridPos) -> Option<usize> {
+        self.contains(pos)
+            .then(|| pos.y as usize * self.width as usize + pos.x as usize)
+    }
+
+    /// Returns the value at a position, or `None` if it is outside the grid.
+    pub fn get(&self, pos: GridPos) -> Option<&T> {
+        self.index_of(pos).map(|i| &self.cells[i])
+    }
+
+    /// Returns the value at a position mutably, or `None` if it is outside the grid.
+    pub fn get_mut(&mut self, pos: GridPos) -> Option<&mut T> {
+        self.index_of(pos).map(|i| &mut self.cells[i])
+    }
+
+    /// Returns the four values next to a position, with `None` for any outside the grid.
+    pub fn neighbors(&self, pos: GridPos) -> CardinalValues<Option<&T>> {
+        pos.neighbors_bounded_in::<C>(self.width, self.height)
+            .map(|v| v.and_then(|v| self.get(v)))
+    }
+
+    /// Returns the four values next to a position mutably, with `None` for any outside the grid.
+    pub fn neighbors_mut(&mut self, pos: GridPos) -> CardinalValues<Option<&mut T>> {
+        let indices = pos
+            .neighbors_bounded_in::<C>(self.width, self.height)
+            .map(|v| v.and_then(|v| self.index_of(v)));
+
+        // the four neighbours are distinct cells, so splitting them off in index order
+        // hands out one borrow for each.
+        let mut order = [
+            Cardinal::East,
+            Cardinal::North,
+            Cardinal::West,
+            Cardinal::South,
+        ]
+        .map(|side| indices[side].map(|index| (index, side)));
+        order.sort_unstable();
+
+        let mut values = CardinalValues::from_fn(|_| None);
+        let mut rest = self.cells.as_mut_slice();
+        let mut start = 0;
+        for (index, side) in order.into_iter().flatten() {
+            let (cell, tail) = core::mem::take(&mut rest)[index - start..]
+                .split_first_mut()
+                .expect("`index_of` only returns indices inside the grid");
+            values[side] = Some(cell);
+            rest = tail;
+            start = index + 1;
+        }
+
+        values
+    }
+
+    /// Walks from a position in a direction until the edge of the grid. The starting
+    /// position itself is not included.
+    pub fn ray(&self, pos: GridPos, direction: Cardinal) -> Ray<'_, T, C> {
+        Ray {
+            grid: self,
+            pos,
+            direction,
+        }
+    }
+
+    /// Gives an iterator over the lines of the grid which run parallel to `side`, starting
+    /// with the line along that edge and moving away from it.
+    ///
+    /// For north and south, each line is a row walked from west to east. For east and west,
+    /// each line is a column walked from south to north.
+    pub fn lines_from(&self, side: Cardinal) -> Lines<'_, T, C> {
+        Lines {
+            grid: self,
+            side,
+            next: 0,
+        }
+    }
+}
+
+impl<T, C: CoordinateSystem> core::ops::Index<GridPos> for Grid<T, C> {
+    type Output = T;
+
+    fn index(&self, index: GridPos) -> &Self::Output {
+        self.get(index).expect("position is outside the grid")
+    }
+}
+
+impl<T, C: CoordinateSystem> core::ops::IndexMut<GridPos> for Grid<T, C> {
+    fn index_mut(&mut self, index: GridPos) -> &mut Self::Output {
+        self.get_mut(index).expect("position is outside the grid")
+    }
+}
+
+/// An iterator walking across a [Grid] in one direction, yielding each position and its value.
+/// This should be constructed with [Grid::ray] or [Grid::lines_from].
+pub struct Ray<'a, T, C = YUp> {
+    grid: &'a Grid<T, C>,
+    pos: GridPos,
+    direction: Cardinal,
+}
+
+impl<'a, T, C: CoordinateSystem> Iterator for Ray<'a, T, C> {
+    type Item = (GridPos, &'a T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let next =
+            self.pos
+                .step_bounded_in::<C>(self.direction, self.grid.width, self.grid.height)?;
+        let value = self.grid.get(next)?;
+        self.pos = next;
+
+        Some((next, value))
+    }
+}
+
+impl<T, C: CoordinateSystem> core::iter::FusedIterator for Ray<'_, T, C> {}
+
+/// An iterator over the rows or columns of a [Grid]. This should be constructed with
+/// [Grid::lines_from].
+pub struct Lines<'a, T, C = YUp> {
+    grid: &'a Grid<T, C>,
+    side: Cardinal,
+    next: u32,
+}
+
+impl<'a, T, C: CoordinateSystem> Iterator for Lines<'a, T, C> {
+    type Item = Ray<'a, T, C>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let count = if self.side.is_vertical() {
+            self.grid.height
+        } else {
+            self.grid.width
+        };
+        if self.next >= count {
+            return None;
+        }
+
+        // each line starts just outside the grid, since a ray steps before it yields.
+        let k = self.next as i32;
+        let (x, y) = self.side.to_ivec2_in::<C>();
+        let (start, direction) = if self.side.is_vertical() {
+            let row = if y > 0 { count as i32 - 1 - k } else { k };
+
+            (GridPos::new(-1, row), Cardinal::East)
+        } else {
+            let column = if x > 0 { count as i32 - 1 - k } else { k };
+            let south = if C::Y_DOWN {
+                self.grid.height as i32
+            } else {
+                -1
+            };
+
+            (GridPos::new(column, south), Cardinal::North)
+        };
+        self.next += 1;
+
+        Some(self.grid.ray(start, direction))
+    }
+}
+
+impl<T, C: CoordinateSystem> core::iter::FusedIterator for Lines<'_, T, C> {}
@@ -0,0 +1,220 @@
+//! Resolvers which turn player input into a [Cardinal] facing.
+
+use crate::{Cardinal, CardinalValues, CoordinateSystem, Corner, YUp};
+
+/// Turns an analog stick into a [Cardinal], with a radial dead zone and hysteresis so that
+/// a stick held near a diagonal doesn't flicker between two facings.
+///
+/// The resolver keeps its previous facing until the stick moves more than `hysteresis`
+/// degrees past the 45 degree boundary between two cardinals.
+#[derive(Debug, PartialEq, Clone, Copy, Default)]
+pub struct StickResolver {
+    /// Sticks with a length at or below this are at rest.
+    pub dead_zone: f32,
+    /// How many degrees past a boundary the stick must go before the facing changes.
+    pub hysteresis: f32,
+    current: Option<Cardinal>,
+}
+
+impl StickResolver {
+    /// Creates a new resolver, with no current facing.
+    pub fn new(dead_zone: f32, hysteresis: f32) -> Self {
+        Self {
+            dead_zone,
+            hysteresis,
+            current: None,
+        }
+    }
+
+    /// The facing from the last update, or `None` if the stick was at rest.
+    pub fn current(&self) -> Option<Cardinal> {
+        self.current
+    }
+
+    /// Forgets the current facing, as if the stick had been released.
+    pub fn reset(&mut self) {
+        self.current = None;
+    }
+
+    /// Updates the resolver with a stick position, where north is +y, and returns the facing.
+    /// Returns `None` if the stick is inside the dead zone.
+    pub fn update(&mut self, x: f32, y: f32) -> Option<Cardinal> {
+        self.update_in::<YUp>(x, y)
+    }
+
+    /// Updates the resolver with a stick position in the given [CoordinateSystem], and returns
+    /// the facing. Returns `None` if the stick is inside the dead zone.
+    pub fn update_in<C: CoordinateSystem>(&mut self, x: f32, y: f32) -> Option<Cardinal> {
+        let y = if C::Y_DOWN { -y } else { y };
+
+        let length = x.hypot(y);
+        if length.is_nan() || length <= self.dead_zone {
+            self.current = None;
+            return None;
+        }
+
+        let angle = y.atan2(x).to_degrees();
+        let held = self.current.filter(|current| {
+            let distance = (angle - current.to_angle() + 180.0).rem_euclid(360.0) - 180.0;
+
+            distance.abs() <= 45.0 + self.hysteresis
+        });
+
+        self.current = held.or_else(|| Some(Cardinal::from_angle(angle)));
+        self.current
+    }
+}
+
+/// How a [DigitalResolver] handles two opposing directions held at once, such as east and west.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default, Hash)]
+pub enum SocdPolicy {
+    /// Opposing directions cancel out.
+    #[default]
+    Neutral,
+    /// The direction pressed most recently wins.
+    LastInput,
+    /// The direction pressed first wins.
+    FirstInput,
+}
+
+/// How a [DigitalResolver] picks a single facing when a horizontal and a vertical direction
+/// are both held.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default, Hash)]
+pub enum AxisPriority {
+    /// The direction pressed most recently wins.
+    #[default]
+    LastInput,
+    /// The direction pressed first wins.
+    FirstInput,
+    /// The horizontal direction always wins.
+    Horizontal,
+    /// The vertical direction always wins.
+    Vertical,
+}
+
+/// The result of a [DigitalResolver] update.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub struct DigitalOutput {
+    /// The single facing, or `None` if nothing is held or everything held cancels out.
+    pub facing: Option<Cardinal>,
+    /// The diagonal, if a horizontal and a vertical direction both survived.
+    pub diagonal: Option<Corner>,
+}
+
+/// Turns four digital inputs, such as a d-pad or arrow keys, into a [Cardinal] facing.
+///
+/// The resolver remembers the order directions were pressed in, so it must be updated
+/// every frame. Directions pressed in the same update count as pressed at once, and ties
+/// in [SocdPolicy] fall back to [SocdPolicy::Neutral], while ties in [AxisPriority] fall
+/// back to [AxisPriority::Horizontal].
+///
+/// ```
+/// # use cardinal_values::{Cardinal, CardinalValues, Corner};
+/// # use cardinal_values::input::{AxisPriority, DigitalResolver, SocdPolicy};
+/// let held = |cardinals: &[Cardinal]| CardinalValues::from_fn(|c| cardinals.contains(&c));
+///
+/// // with `LastInput`, the newest of two opposing directions wins, and the older one
+/// // comes back once it's released.
+/// let mut last = DigitalResolver::new(SocdPolicy::LastInput, AxisPriority::LastInput);
+/// assert_eq!(last.update(held(&[Cardinal::West])).facing, Some(Cardinal::West));
+/// let both = held(&[Cardinal::West, Cardinal::East]);
+/// assert_eq!(last.update(both).facing, Some(Cardinal::East));
+/// assert_eq!(last.update(held(&[Cardinal::West])).facing, Some(Cardinal::West));
+///
+/// // with `FirstInput`, the direction held first keeps winning.
+/// let mut first = DigitalResolver::new(SocdPolicy::FirstInput, AxisPriority::LastInput);
+/// assert_eq!(first.update(held(&[Cardinal::West])).facing, Some(Cardinal::West));
+/// assert_eq!(first.update(both).facing, Some(Cardinal::West));
+///
+/// // opposing directions pressed in the same update cancel out, whatever the policy.
+/// let mut tied = DigitalResolver::new(SocdPolicy::LastInput, AxisPriority::LastInput);
+/// assert_eq!(tied.update(both).facing, None);
+///
+/// // across axes, `AxisPriority::LastInput` faces the newest direction, and both
+/// // directions still make a diagonal.
+/// let mut axes = DigitalResolver::new(SocdPolicy::Neutral, AxisPriority::LastInput);
+/// axes.update(held(&[Cardinal::East]));
+/// let output = axes.update(held(&[Cardinal::East, Cardinal::North]));
+/// assert_eq!(output.facing, Some(Cardinal::North));
+/// assert_eq!(output.diagonal, Some(Corner::NorthEast));
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default, Hash)]
+pub struct DigitalResolver {
+    /// How opposing directions are resolved.
+    pub socd: SocdPolicy,
+    /// How a horizontal and a vertical direction are resolved into one facing.
+    pub axis_priority: AxisPriority,
+    pressed_at: CardinalValues<Option<u64>>,
+    tick: u64,
+}
+
+impl DigitalResolver {
+    /// Creates a new resolver, with nothing held.
+    pub fn new(socd: SocdPolicy, axis_priority: AxisPriority) -> Self {
+        Self {
+            socd,
+            axis_priority,
+            ..Self::default()
+        }
+    }
+
+    /// Forgets every held direction, as if they had all been released.
+    pub fn reset(&mut self) {
+        self.pressed_at = CardinalValues::default();
+    }
+
+    /// Updates the resolver with the directions held this frame, and returns the facing.
+    pub fn update(&mut self, held: CardinalValues<bool>) -> DigitalOutput {
+        self.tick += 1;
+
+        let tick = self.tick;
+        let stamp = |held: bool, pressed_at: Option<u64>| match held {
+            true => pressed_at.or(Some(tick)),
+            false => None,
+        };
+        self.pressed_at = CardinalValues {
+            east: stamp(held.east, self.pressed_at.east),
+            north: stamp(held.north, self.pressed_at.north),
+            west: stamp(held.west, self.pressed_at.west),
+            south: stamp(held.south, self.pressed_at.south),
+        };
+
+        let horizontal = self.resolve_axis(Cardinal::East, Cardinal::West);
+        let vertical = self.resolve_axis(Cardinal::North, Cardinal::South);
+
+        let facing = match (horizontal, vertical) {
+            (Some((h, h_at)), Some((v, v_at))) => match self.axis_priority {
+                AxisPriority::Horizontal => Some(h),
+                AxisPriority::Vertical => Some(v),
+                AxisPriority::LastInput if v_at > h_at => Some(v),
+                AxisPriority::FirstInput if v_at < h_at => Some(v),
+                _ => Some(h),
+            },
+            (h, v) => h.or(v).map(|(c, _)| c),
+        };
+
+        let diagonal = horizontal
+            .zip(vertical)
+            .and_then(|((h, _), (v, _))| Corner::from_cardinals(h, v));
+
+        DigitalOutput { facing, diagonal }
+    }
+
+    /// Resolves two opposing directions into at most one, with the time it was pressed.
+    fn resolve_axis(&self, a: Cardinal, b: Cardinal) -> Option<(Cardinal, u64)> {
+        match (self.pressed_at[a], self.pressed_at[b]) {
+            (Some(a_at), None) => Some((a, a_at)),
+            (None, Some(b_at)) => Some((b, b_at)),
+            (None, None) => None,
+            (Some(a_at), Some(b_at)) => {
+                let winner = match self.socd {
+                    SocdPolicy::Neutral => None,
+                    SocdPolicy::LastInput => (a_at != b_at).then_some(a_at > b_at),
+                    SocdPolicy::FirstInput => (a_at != b_at).then_some(a_at < b_at),
+                };
+
+                winner.map(|a_wins| if a_wins { (a, a_at) } else { (b, b_at) })
+            }
+        }
+    }
+}
@@ -1,5 +1,39 @@
 //! A tiny library providing support for `Cardinal`, an enum of the four cardinal directions,
 //! and `CardinalValues`, which is a struct indexed by `Cardinal` with a value at each direction.
+//!
+//! Vector and angle conversions assume north is up by default. The `_in` variants take a
+//! `CoordinateSystem`, such as `YDown`, for renderers and tile maps where it isn't. Compass
+//! bearings, where north is 0 and angles grow clockwise, are available with `to_bearing`.
+//! For `f64` or radians, use the typed `Angle`.
+//!
+//! `CardinalValues` can be indexed, mutably too, by `Cardinal`, and has the usual combinators,
+//! such as `zip`, `try_map` and `transpose`. It iterates in the same order as
+//! `Cardinal::iter_values`: east, north, west, south.
+//! Numeric `CardinalValues`, such as insets or forces, support the arithmetic operators, both
+//! side by side and with a scalar, and reductions such as `total` and `argmax`.
+//!
+//! `Cardinal` can be written and read as words, letters or arrows, with `Cardinal::display`,
+//! `FromStr` and `TryFrom<char>`. With the `serde` feature, the `repr` module offers other
+//! serde representations for `Cardinal` and `CardinalValues`. The [shorthand] module reads
+//! and writes `CardinalValues` as CSS-style shorthand, such as `"4px 8px"`.
+//!
+//! For eight-way movement, `Ordinal` adds the four diagonals between the cardinals. `Corner`
+//! names just those diagonals, and `CornerValues` holds a value at each of them. `NeighborValues`
+//! holds all eight at once.
+//!
+//! `CardinalValues` can be rotated and mirrored, to turn a tile's per-side data along with it.
+//! `D4` describes the rotations and reflections of a square, and applies them to `Cardinal`
+//! and `CardinalValues`. The [flip] module reads tile flip flags from map editors into a `D4`.
+//!
+//! `CardinalSet` is a compact set of cardinals, for when all you need is which sides are on.
+//! The [autotile] module turns neighbour flags into tile indices.
+//!
+//! `GridPos` is a position on an integer grid which can step in each `Cardinal`, and `Grid`
+//! stores a value at each of those positions. `CardinalPath` is a sequence of steps across
+//! such a grid. The [boxdraw] module draws a grid of connections with box-drawing characters.
+//!
+//! The [input] module turns analog sticks and digital directions into a facing, and
+//! `RelativeDirection` turns that facing left, right or around.
 
 #![deny(rust_2018_idioms)]
 #![allow(clippy::bool_comparison)]
@@ -9,7 +43,38 @@
 #![warn(clippy::undocumented_unsafe_blocks)]
 #![warn(missing_docs)]
 
-use core::ops;
+use core::{iter::FusedIterator, ops};
+
+mod angle;
+mod arith;
+pub mod autotile;
+pub mod boxdraw;
+mod coords;
+mod corner;
+mod d4;
+pub mod flip;
+mod glyph;
+mod grid;
+pub mod input;
+mod neighbor;
+mod ordinal;
+mod path;
+mod relative;
+#[cfg(feature = "serde")]
+pub mod repr;
+mod set;
+pub mod shorthand;
+pub use angle::{Angle, NonFiniteAngle};
+pub use coords::{angle_to_bearing, bearing_to_angle, CoordinateSystem, Screen, YDown, YUp};
+pub use corner::{Corner, CornerEnumeratedIterator, CornerIterator, CornerValues};
+pub use d4::D4;
+pub use glyph::{CardinalFormat, DisplayCardinal, ParseCardinalError};
+pub use grid::{Grid, GridPos, Lines, Ray};
+pub use neighbor::{NeighborEnumeratedIterator, NeighborIterator, NeighborValues};
+pub use ordinal::Ordinal;
+pub use path::{CardinalPath, ParsePathError};
+pub use relative::{RelativeDirection, RelativeValues};
+pub use set::{CardinalSet, InvalidSetBits};
 
 /// An enumerator for the simple cardinal directions.
 #[derive(Debug, PartialEq, Eq, Clone, Copy, PartialOrd, Ord)]
@@ -26,7 +91,8 @@ pub enum Cardinal {
 }
 
 impl Cardinal {
-    /// Rotates a cardinal.
+    /// Rotates a cardinal by `amount` quarter turns. Positive amounts rotate counter-clockwise,
+    /// so `Cardinal::East.rotate(1)` is `Cardinal::North`.
     #[must_use = "this returns the result of the operation, \
     without modifying the original"]
     pub fn rotate(self, amount: i32) -> Self {
@@ -49,6 +115,27 @@ impl Cardinal {
         }
     }
 
+    /// Rotates a cardinal a quarter turn clockwise, so east becomes south.
+    #[must_use = "this returns the result of the operation, \
+    without modifying the original"]
+    pub fn clockwise(self) -> Self {
+        self.rotate(-1)
+    }
+
+    /// Rotates a cardinal a quarter turn counter-clockwise, so east becomes north.
+    #[must_use = "this returns the result of the operation, \
+    without modifying the original"]
+    pub fn counter_clockwise(self) -> Self {
+        self.rotate(1)
+    }
+
+    /// Returns the cardinal pointing the other way, so east becomes west.
+    #[must_use = "this returns the result of the operation, \
+    without modifying the original"]
+    pub fn opposite(self) -> Self {
+        self.rotate(2)
+    }
+
     /// Gives an iterator over the four cardinals
     pub fn iter_values() -> impl Iterator<Item = Self> {
         [
@@ -71,6 +158,11 @@ impl Cardinal {
         }
     }
 
+    /// Converts to a simple tuple int form, in the given [CoordinateSystem].
+    pub fn to_ivec2_in<C: CoordinateSystem>(self) -> (i32, i32) {
+        coords::offset_in::<C>(self.to_ivec2())
+    }
+
     /// Returns an angle representing the Cardinal
     pub fn to_angle(self) -> f32 {
         match self {
@@ -83,7 +175,14 @@ impl Cardinal {
 
     /// This returns a cardinal as a best guess for floats. If you give an exactly
     /// divisible by 90 degrees, it'll work just the way you expect.
+    ///
+    /// Infinite and NaN angles give East. Use [try_from_angle](Cardinal::try_from_angle)
+    /// to catch them instead.
     pub fn from_angle(angle: f32) -> Cardinal {
+        Self::from_degrees(angle as f64)
+    }
+
+    fn from_degrees(angle: f64) -> Cardinal {
         let angle = angle.rem_euclid(360.0);
 
         if (45.0..135.0).contains(&angle) {
@@ -97,6 +196,79 @@ impl Cardinal {
         }
     }
 
+    /// Returns an [Angle] representing the Cardinal.
+    pub fn angle(self) -> Angle {
+        Angle::from_degrees(self.to_angle() as f64)
+    }
+
+    /// This returns a cardinal as a best guess for an [Angle], or an error if the angle
+    /// is infinite or NaN.
+    pub fn try_from_angle(angle: Angle) -> Result<Cardinal, NonFiniteAngle> {
+        if angle.is_finite() {
+            Ok(Self::from_degrees(angle.degrees()))
+        } else {
+            Err(NonFiniteAngle)
+        }
+    }
+
+    /// Returns an angle representing the Cardinal, in the given [CoordinateSystem].
+    pub fn to_angle_in<C: CoordinateSystem>(self) -> f32 {
+        coords::angle_in::<C>(self.to_angle())
+    }
+
+    /// This returns a cardinal as a best guess for floats, in the given [CoordinateSystem].
+    pub fn from_angle_in<C: CoordinateSystem>(angle: f32) -> Cardinal {
+        Self::from_angle(coords::angle_in::<C>(angle))
+    }
+
+    /// Returns the compass bearing of the Cardinal in degrees, where north is 0 and bearings grow
+    /// clockwise, so east is 90.
+    ///
+    /// Bearings and [to_angle](Cardinal::to_angle) convert into each other with
+    /// [bearing_to_angle] and [angle_to_bearing]:
+    ///
+    /// ```
+    /// # use cardinal_values::{angle_to_bearing, bearing_to_angle, Cardinal};
+    /// for cardinal in Cardinal::iter_values() {
+    ///     assert_eq!(Cardinal::from_bearing(cardinal.to_bearing()), cardinal);
+    ///     assert_eq!(angle_to_bearing(cardinal.to_angle()), cardinal.to_bearing());
+    ///     assert_eq!(bearing_to_angle(cardinal.to_bearing()), cardinal.to_angle());
+    ///     assert_eq!(Cardinal::from_angle(bearing_to_angle(cardinal.to_bearing())), cardinal);
+    /// }
+    /// ```
+    pub fn to_bearing(self) -> f32 {
+        match self {
+            Cardinal::North => 0.0,
+            Cardinal::East => 90.0,
+            Cardinal::South => 180.0,
+            Cardinal::West => 270.0,
+        }
+    }
+
+    /// This returns a cardinal as a best guess for a compass bearing in degrees. If you give
+    /// an exactly divisible by 90 degrees, it'll work just the way you expect.
+    pub fn from_bearing(bearing: f32) -> Cardinal {
+        Self::from_angle(bearing_to_angle(bearing))
+    }
+
+    /// Returns the compass bearing of the Cardinal in radians, where north is 0 and bearings
+    /// grow clockwise.
+    ///
+    /// ```
+    /// # use cardinal_values::Cardinal;
+    /// for cardinal in Cardinal::iter_values() {
+    ///     assert_eq!(Cardinal::from_bearing_radians(cardinal.to_bearing_radians()), cardinal);
+    /// }
+    /// ```
+    pub fn to_bearing_radians(self) -> f32 {
+        self.to_bearing().to_radians()
+    }
+
+    /// This returns a cardinal as a best guess for a compass bearing in radians.
+    pub fn from_bearing_radians(bearing: f32) -> Cardinal {
+        Self::from_bearing(bearing.to_degrees())
+    }
+
     /// Is either West or East
     pub fn is_horizontal(self) -> bool {
         matches!(self, Self::East | Self::West)
@@ -108,6 +280,14 @@ impl Cardinal {
     }
 }
 
+impl TryFrom<Angle> for Cardinal {
+    type Error = NonFiniteAngle;
+
+    fn try_from(angle: Angle) -> Result<Self, Self::Error> {
+        Self::try_from_angle(angle)
+    }
+}
+
 impl core::fmt::Display for Cardinal {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         let word = match self {
@@ -137,6 +317,101 @@ pub struct CardinalValues<T> {
 }
 
 impl<T> CardinalValues<T> {
+    /// Creates a [CardinalValues] by calling `f` for each cardinal, in the order east, north,
+    /// west, south.
+    pub fn from_fn<F>(mut f: F) -> Self
+    where
+        F: FnMut(Cardinal) -> T,
+    {
+        Self {
+            east: f(Cardinal::East),
+            north: f(Cardinal::North),
+            west: f(Cardinal::West),
+            south: f(Cardinal::South),
+        }
+    }
+
+    /// Creates a [CardinalValues] with the same value at every cardinal.
+    pub fn splat(value: T) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            east: value.clone(),
+            north: value.clone(),
+            west: value.clone(),
+            south: value,
+        }
+    }
+
+    /// Converts from `&CardinalValues<T>` to `CardinalValues<&T>`.
+    pub fn as_ref(&self) -> CardinalValues<&T> {
+        CardinalValues {
+            east: &self.east,
+            north: &self.north,
+            west: &self.west,
+            south: &self.south,
+        }
+    }
+
+    /// Converts from `&mut CardinalValues<T>` to `CardinalValues<&mut T>`.
+    pub fn as_mut(&mut self) -> CardinalValues<&mut T> {
+        CardinalValues {
+            east: &mut self.east,
+            north: &mut self.north,
+            west: &mut self.west,
+            south: &mut self.south,
+        }
+    }
+
+    /// Returns a reference to the value at a cardinal.
+    pub fn get(&self, cardinal: Cardinal) -> &T {
+        match cardinal {
+            Cardinal::East => &self.east,
+            Cardinal::North => &self.north,
+            Cardinal::West => &self.west,
+            Cardinal::South => &self.south,
+        }
+    }
+
+    /// Returns a mutable reference to the value at a cardinal.
+    pub fn get_mut(&mut self, cardinal: Cardinal) -> &mut T {
+        match cardinal {
+            Cardinal::East => &mut self.east,
+            Cardinal::North => &mut self.north,
+            Cardinal::West => &mut self.west,
+            Cardinal::South => &mut self.south,
+        }
+    }
+
+    /// Returns mutable references to the values at several cardinals at once, in the order
+    /// they're given. Returns `None` if any cardinal is given more than once.
+    pub fn get_many_mut<const N: usize>(
+        &mut self,
+        cardinals: [Cardinal; N],
+    ) -> Option<[&mut T; N]> {
+        // each reference can only be taken once, so a repeated cardinal finds `None`.
+        let mut slots = self.as_mut().map(Some);
+        let found = cardinals.map(|v| slots[v].take());
+        if found.iter().any(Option::is_none) {
+            return None;
+        }
+
+        Some(found.map(|v| v.expect("every value was checked above")))
+    }
+
+    /// Puts a value at a cardinal, returning the value which was there before.
+    pub fn replace(&mut self, cardinal: Cardinal, value: T) -> T {
+        core::mem::replace(self.get_mut(cardinal), value)
+    }
+
+    /// Swaps the values at two cardinals. Swapping a cardinal with itself does nothing.
+    pub fn swap(&mut self, a: Cardinal, b: Cardinal) {
+        if let Some([a, b]) = self.get_many_mut([a, b]) {
+            core::mem::swap(a, b);
+        }
+    }
+
     /// Converts a [CardinalValues] from one type to another.
     pub fn map<B, F>(self, mut f: F) -> CardinalValues<B>
     where
@@ -149,76 +424,205 @@ impl<T> CardinalValues<T> {
             south: f(self.south),
         }
     }
+
+    /// Converts a [CardinalValues] from one type to another, giving `f` the cardinal of
+    /// each value too.
+    pub fn map_with_cardinal<B, F>(self, mut f: F) -> CardinalValues<B>
+    where
+        F: FnMut(Cardinal, T) -> B,
+    {
+        CardinalValues {
+            east: f(Cardinal::East, self.east),
+            north: f(Cardinal::North, self.north),
+            west: f(Cardinal::West, self.west),
+            south: f(Cardinal::South, self.south),
+        }
+    }
+
+    /// Converts a [CardinalValues] from one type to another with a fallible function,
+    /// returning the first error in the order east, north, west, south.
+    pub fn try_map<B, E, F>(self, mut f: F) -> Result<CardinalValues<B>, E>
+    where
+        F: FnMut(T) -> Result<B, E>,
+    {
+        Ok(CardinalValues {
+            east: f(self.east)?,
+            north: f(self.north)?,
+            west: f(self.west)?,
+            south: f(self.south)?,
+        })
+    }
+
+    /// Pairs each value with the value at the same cardinal in `other`.
+    pub fn zip<U>(self, other: CardinalValues<U>) -> CardinalValues<(T, U)> {
+        self.zip_with(other, |a, b| (a, b))
+    }
+
+    /// Combines each value with the value at the same cardinal in `other`.
+    pub fn zip_with<U, B, F>(self, other: CardinalValues<U>, mut f: F) -> CardinalValues<B>
+    where
+        F: FnMut(T, U) -> B,
+    {
+        CardinalValues {
+            east: f(self.east, other.east),
+            north: f(self.north, other.north),
+            west: f(self.west, other.west),
+            south: f(self.south, other.south),
+        }
+    }
+}
+
+impl<T> CardinalValues<Option<T>> {
+    /// Returns `Some` if every value is `Some`, and `None` otherwise.
+    pub fn transpose(self) -> Option<CardinalValues<T>> {
+        Some(CardinalValues {
+            east: self.east?,
+            north: self.north?,
+            west: self.west?,
+            south: self.south?,
+        })
+    }
+}
+
+impl<T, E> CardinalValues<Result<T, E>> {
+    /// Returns `Ok` if every value is `Ok`, and the first error in the order east, north,
+    /// west, south otherwise.
+    pub fn transpose(self) -> Result<CardinalValues<T>, E> {
+        self.try_map(|v| v)
+    }
 }
 
 impl<T> ops::Index<Cardinal> for CardinalValues<T> {
     type Output = T;
 
     fn index(&self, index: Cardinal) -> &Self::Output {
-        match index {
-            Cardinal::East => &self.east,
-            Cardinal::North => &self.north,
-            Cardinal::West => &self.west,
-            Cardinal::South => &self.south,
-        }
+        self.get(index)
     }
 }
 
-impl<T: Copy> IntoIterator for CardinalValues<T> {
+impl<T> ops::IndexMut<Cardinal> for CardinalValues<T> {
+    fn index_mut(&mut self, index: Cardinal) -> &mut Self::Output {
+        self.get_mut(index)
+    }
+}
+
+impl<T> IntoIterator for CardinalValues<T> {
     type Item = T;
 
     type IntoIter = CardinalIterator<T>;
 
     fn into_iter(self) -> Self::IntoIter {
-        CardinalIterator(self, 0)
+        CardinalIterator(
+            [
+                (Cardinal::East, self.east),
+                (Cardinal::North, self.north),
+                (Cardinal::West, self.west),
+                (Cardinal::South, self.south),
+            ]
+            .into_iter(),
+        )
+    }
+}
+
+impl<'a, T> IntoIterator for &'a CardinalValues<T> {
+    type Item = &'a T;
+
+    type IntoIter = CardinalIterator<&'a T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.as_ref().into_iter()
     }
 }
 
-/// An iterator over a CardinalValues.
-pub struct CardinalIterator<T>(CardinalValues<T>, usize);
+impl<'a, T> IntoIterator for &'a mut CardinalValues<T> {
+    type Item = &'a mut T;
+
+    type IntoIter = CardinalIterator<&'a mut T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.as_mut().into_iter()
+    }
+}
+
+impl<T> CardinalValues<T> {
+    /// Gives an iterator over references to the values, in the same order as
+    /// [Cardinal::iter_values].
+    pub fn iter(&self) -> CardinalIterator<&T> {
+        self.into_iter()
+    }
+
+    /// Gives an iterator over mutable references to the values, in the same order as
+    /// [Cardinal::iter_values].
+    pub fn iter_mut(&mut self) -> CardinalIterator<&mut T> {
+        self.into_iter()
+    }
+}
+
+/// An iterator over a CardinalValues, in the same order as [Cardinal::iter_values]: east,
+/// north, west, south.
+#[derive(Debug, Clone)]
+pub struct CardinalIterator<T>(core::array::IntoIter<(Cardinal, T), 4>);
 
 impl<T> CardinalIterator<T> {
     /// Converts this iterator into an Enumerated one, where each value has its Cardinal given.
+    ///
+    /// ```
+    /// # use cardinal_values::{Cardinal, CardinalValues};
+    /// let values = CardinalValues { east: 'e', north: 'n', west: 'w', south: 's' };
+    /// for (cardinal, value) in values.into_iter().enumerate() {
+    ///     assert_eq!(values[cardinal], value);
+    /// }
+    /// ```
     pub fn enumerate(self) -> CardinalEnumeratedIterator<T> {
-        CardinalEnumeratedIterator(self.0, self.1)
+        CardinalEnumeratedIterator(self.0)
     }
 }
 
-impl<T: Copy> Iterator for CardinalIterator<T> {
+impl<T> Iterator for CardinalIterator<T> {
     type Item = T;
 
     fn next(&mut self) -> Option<Self::Item> {
-        let found = match self.1 {
-            0 => Some(self.0.east),
-            1 => Some(self.0.west),
-            2 => Some(self.0.north),
-            3 => Some(self.0.south),
-            _ => return None,
-        };
+        self.0.next().map(|(_, v)| v)
+    }
 
-        self.1 += 1;
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+}
 
-        found
+impl<T> DoubleEndedIterator for CardinalIterator<T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.0.next_back().map(|(_, v)| v)
     }
 }
 
+impl<T> ExactSizeIterator for CardinalIterator<T> {}
+
+impl<T> FusedIterator for CardinalIterator<T> {}
+
 /// An enumerated iterator for [CardinalValues]. This should be constructed with the `enumerate` method
 /// on [CardinalIterator].
-pub struct CardinalEnumeratedIterator<T>(CardinalValues<T>, usize);
-impl<T: Copy> Iterator for CardinalEnumeratedIterator<T> {
+#[derive(Debug, Clone)]
+pub struct CardinalEnumeratedIterator<T>(core::array::IntoIter<(Cardinal, T), 4>);
+
+impl<T> Iterator for CardinalEnumeratedIterator<T> {
     type Item = (Cardinal, T);
 
     fn next(&mut self) -> Option<Self::Item> {
-        let found = match self.1 {
-            0 => Some((Cardinal::North, self.0.north)),
-            1 => Some((Cardinal::West, self.0.west)),
-            2 => Some((Cardinal::South, self.0.south)),
-            3 => Some((Cardinal::East, self.0.east)),
-            _ => return None,
-        };
+        self.0.next()
+    }
 
-        self.1 += 1;
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+}
 
-        found
+impl<T> DoubleEndedIterator for CardinalEnumeratedIterator<T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.0.next_back()
     }
 }
+
+impl<T> ExactSizeIterator for CardinalEnumeratedIterator<T> {}
+
+impl<T> FusedIterator for CardinalEnumeratedIterator<T> {}
@@ -0,0 +1,175 @@
+use core::ops;
+
+use crate::{Cardinal, CardinalValues, Corner, CornerValues, Ordinal};
+
+/// A struct which a value assigned to each of the eight neighbours of a cell: one at each
+/// cardinal and one at each corner. This is useful for 8-connected grid algorithms.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, PartialOrd, Ord, Default, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct NeighborValues<T> {
+    /// The value assigned to east.
+    pub east: T,
+    /// The value assigned to the north east.
+    pub north_east: T,
+    /// The value assigned to north.
+    pub north: T,
+    /// The value assigned to the north west.
+    pub north_west: T,
+    /// The value assigned to west.
+    pub west: T,
+    /// The value assigned to the south west.
+    pub south_west: T,
+    /// The value assigned to south.
+    pub south: T,
+    /// The value assigned to the south east.
+    pub south_east: T,
+}
+
+impl<T> NeighborValues<T> {
+    /// Converts a [NeighborValues] from one type to another.
+    pub fn map<B, F>(self, mut f: F) -> NeighborValues<B>
+    where
+        F: FnMut(T) -> B,
+    {
+        NeighborValues {
+            east: f(self.east),
+            north_east: f(self.north_east),
+            north: f(self.north),
+            north_west: f(self.north_west),
+            west: f(self.west),
+            south_west: f(self.south_west),
+            south: f(self.south),
+            south_east: f(self.south_east),
+        }
+    }
+
+    /// Joins a set of sides and a set of corners into one [NeighborValues].
+    pub fn from_parts(sides: CardinalValues<T>, corners: CornerValues<T>) -> Self {
+        NeighborValues {
+            east: sides.east,
+            north_east: corners.north_east,
+            north: sides.north,
+            north_west: corners.north_west,
+            west: sides.west,
+            south_west: corners.south_west,
+            south: sides.south,
+            south_east: corners.south_east,
+        }
+    }
+
+    /// Splits this into its sides and its corners.
+    pub fn into_parts(self) -> (CardinalValues<T>, CornerValues<T>) {
+        let sides = CardinalValues {
+            east: self.east,
+            north: self.north,
+            west: self.west,
+            south: self.south,
+        };
+        let corners = CornerValues {
+            north_east: self.north_east,
+            north_west: self.north_west,
+            south_west: self.south_west,
+            south_east: self.south_east,
+        };
+
+        (sides, corners)
+    }
+
+    /// Gives a view of the four sides.
+    pub fn sides(&self) -> CardinalValues<&T> {
+        CardinalValues {
+            east: &self.east,
+            north: &self.north,
+            west: &self.west,
+            south: &self.south,
+        }
+    }
+
+    /// Gives a view of the four corners.
+    pub fn corners(&self) -> CornerValues<&T> {
+        CornerValues {
+            north_east: &self.north_east,
+            north_west: &self.north_west,
+            south_west: &self.south_west,
+            south_east: &self.south_east,
+        }
+    }
+}
+
+impl<T> ops::Index<Ordinal> for NeighborValues<T> {
+    type Output = T;
+
+    fn index(&self, index: Ordinal) -> &Self::Output {
+        match index {
+            Ordinal::East => &self.east,
+            Ordinal::NorthEast => &self.north_east,
+            Ordinal::North => &self.north,
+            Ordinal::NorthWest => &self.north_west,
+            Ordinal::West => &self.west,
+            Ordinal::SouthWest => &self.south_west,
+            Ordinal::South => &self.south,
+            Ordinal::SouthEast => &self.south_east,
+        }
+    }
+}
+
+impl<T> ops::Index<Cardinal> for NeighborValues<T> {
+    type Output = T;
+
+    fn index(&self, index: Cardinal) -> &Self::Output {
+        &self[Ordinal::from(index)]
+    }
+}
+
+impl<T> ops::Index<Corner> for NeighborValues<T> {
+    type Output = T;
+
+    fn index(&self, index: Corner) -> &Self::Output {
+        &self[Ordinal::from(index)]
+    }
+}
+
+impl<T: Copy> IntoIterator for NeighborValues<T> {
+    type Item = T;
+
+    type IntoIter = NeighborIterator<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        NeighborIterator(self, 0)
+    }
+}
+
+/// An iterator over a NeighborValues, in the same order as [Ordinal::iter_values].
+pub struct NeighborIterator<T>(NeighborValues<T>, usize);
+
+impl<T> NeighborIterator<T> {
+    /// Converts this iterator into an Enumerated one, where each value has its Ordinal given.
+    pub fn enumerate(self) -> NeighborEnumeratedIterator<T> {
+        NeighborEnumeratedIterator(self)
+    }
+}
+
+impl<T: Copy> Iterator for NeighborIterator<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let found = Ordinal::iter_values().nth(self.1).map(|v| self.0[v]);
+
+        self.1 += 1;
+
+        found
+    }
+}
+
+/// An enumerated iterator for [NeighborValues]. This should be constructed with the `enumerate` method
+/// on [NeighborIterator].
+pub struct NeighborEnumeratedIterator<T>(NeighborIterator<T>);
+impl<T: Copy> Iterator for NeighborEnumeratedIterator<T> {
+    type Item = (Ordinal, T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let ordinal = Ordinal::iter_values().nth(self.0 .1)?;
+
+        self.0.next().map(|v| (ordinal, v))
+    }
+}
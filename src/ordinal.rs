@@ -0,0 +1,273 @@
+use crate::{
+    angle_to_bearing, bearing_to_angle, coords, Angle, Cardinal, CoordinateSystem, Corner,
+    NonFiniteAngle,
+};
+
+/// An enumerator for the eight directions: the four cardinals and the four diagonals between them.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Ordinal {
+    /// East, or (1, 0)
+    East,
+    /// NorthEast, or (1, 1)
+    NorthEast,
+    /// North, or (0, 1)
+    North,
+    /// NorthWest, or (-1, 1)
+    NorthWest,
+    /// West, or (-1, 0)
+    West,
+    /// SouthWest, or (-1, -1)
+    SouthWest,
+    /// South, or (0, -1)
+    South,
+    /// SouthEast, or (1, -1)
+    SouthEast,
+}
+
+impl Ordinal {
+    /// Rotates an ordinal in steps of 45 degrees. Positive amounts rotate counter-clockwise,
+    /// so `Ordinal::East.rotate(1)` is `Ordinal::NorthEast`.
+    #[must_use = "this returns the result of the operation, \
+    without modifying the original"]
+    pub fn rotate(self, amount: i32) -> Self {
+        Self::from_index((self.index() as i32 + amount).rem_euclid(8) as usize)
+    }
+
+    /// Gives an iterator over the eight ordinals, starting at east and going counter-clockwise.
+    pub fn iter_values() -> impl Iterator<Item = Self> {
+        [
+            Ordinal::East,
+            Ordinal::NorthEast,
+            Ordinal::North,
+            Ordinal::NorthWest,
+            Ordinal::West,
+            Ordinal::SouthWest,
+            Ordinal::South,
+            Ordinal::SouthEast,
+        ]
+        .into_iter()
+    }
+
+    /// Converts to a simple tuple int form.
+    /// This assumes that north is up.
+    pub fn to_ivec2(self) -> (i32, i32) {
+        match self {
+            Ordinal::East => (1, 0),
+            Ordinal::NorthEast => (1, 1),
+            Ordinal::North => (0, 1),
+            Ordinal::NorthWest => (-1, 1),
+            Ordinal::West => (-1, 0),
+            Ordinal::SouthWest => (-1, -1),
+            Ordinal::South => (0, -1),
+            Ordinal::SouthEast => (1, -1),
+        }
+    }
+
+    /// Converts to a simple tuple int form, in the given [CoordinateSystem].
+    pub fn to_ivec2_in<C: CoordinateSystem>(self) -> (i32, i32) {
+        coords::offset_in::<C>(self.to_ivec2())
+    }
+
+    /// Returns an angle representing the Ordinal
+    pub fn to_angle(self) -> f32 {
+        self.index() as f32 * 45.0
+    }
+
+    /// Returns an angle representing the Ordinal, in the given [CoordinateSystem].
+    pub fn to_angle_in<C: CoordinateSystem>(self) -> f32 {
+        coords::angle_in::<C>(self.to_angle())
+    }
+
+    /// This returns an ordinal as a best guess for floats. If you give an exactly
+    /// divisible by 45 degrees, it'll work just the way you expect.
+    ///
+    /// Infinite and NaN angles give East. Use [try_from_angle](Ordinal::try_from_angle)
+    /// to catch them instead.
+    pub fn from_angle(angle: f32) -> Ordinal {
+        Self::from_degrees(angle as f64)
+    }
+
+    fn from_degrees(angle: f64) -> Ordinal {
+        let angle = angle.rem_euclid(360.0);
+        let index = ((angle + 22.5) / 45.0) as usize;
+
+        Self::from_index(index % 8)
+    }
+
+    /// Returns an [Angle] representing the Ordinal.
+    pub fn angle(self) -> Angle {
+        Angle::from_degrees(self.to_angle() as f64)
+    }
+
+    /// This returns an ordinal as a best guess for an [Angle], or an error if the angle
+    /// is infinite or NaN.
+    pub fn try_from_angle(angle: Angle) -> Result<Ordinal, NonFiniteAngle> {
+        if angle.is_finite() {
+            Ok(Self::from_degrees(angle.degrees()))
+        } else {
+            Err(NonFiniteAngle)
+        }
+    }
+
+    /// This returns an ordinal as a best guess for floats, in the given [CoordinateSystem].
+    pub fn from_angle_in<C: CoordinateSystem>(angle: f32) -> Ordinal {
+        Self::from_angle(coords::angle_in::<C>(angle))
+    }
+
+    /// Returns the compass bearing of the Ordinal in degrees, where north is 0 and bearings grow
+    /// clockwise, so north east is 45.
+    ///
+    /// ```
+    /// # use cardinal_values::{angle_to_bearing, Ordinal};
+    /// for ordinal in Ordinal::iter_values() {
+    ///     assert_eq!(Ordinal::from_bearing(ordinal.to_bearing()), ordinal);
+    ///     assert_eq!(angle_to_bearing(ordinal.to_angle()), ordinal.to_bearing());
+    /// }
+    /// ```
+    pub fn to_bearing(self) -> f32 {
+        angle_to_bearing(self.to_angle())
+    }
+
+    /// This returns an ordinal as a best guess for a compass bearing in degrees.
+    pub fn from_bearing(bearing: f32) -> Ordinal {
+        Self::from_angle(bearing_to_angle(bearing))
+    }
+
+    /// Returns the compass bearing of the Ordinal in radians, where north is 0 and bearings
+    /// grow clockwise.
+    ///
+    /// ```
+    /// # use cardinal_values::Ordinal;
+    /// for ordinal in Ordinal::iter_values() {
+    ///     assert_eq!(Ordinal::from_bearing_radians(ordinal.to_bearing_radians()), ordinal);
+    /// }
+    /// ```
+    pub fn to_bearing_radians(self) -> f32 {
+        self.to_bearing().to_radians()
+    }
+
+    /// This returns an ordinal as a best guess for a compass bearing in radians.
+    pub fn from_bearing_radians(bearing: f32) -> Ordinal {
+        Self::from_bearing(bearing.to_degrees())
+    }
+
+    /// Is one of the four cardinals.
+    pub fn is_cardinal(self) -> bool {
+        matches!(
+            self,
+            Ordinal::East | Ordinal::North | Ordinal::West | Ordinal::South
+        )
+    }
+
+    /// Is one of the four diagonals.
+    pub fn is_diagonal(self) -> bool {
+        !self.is_cardinal()
+    }
+
+    /// Splits a diagonal into its two cardinal components, in counter-clockwise order,
+    /// so `Ordinal::NorthEast` splits into `(Cardinal::East, Cardinal::North)`.
+    ///
+    /// Returns `None` if this is already a cardinal.
+    pub fn split(self) -> Option<(Cardinal, Cardinal)> {
+        Corner::try_from(self).ok().map(Corner::split)
+    }
+
+    /// Combines two cardinals into the diagonal between them, in either order.
+    ///
+    /// Returns `None` if the two cardinals are equal or opposite.
+    pub fn from_cardinals(a: Cardinal, b: Cardinal) -> Option<Ordinal> {
+        if a.is_horizontal() == b.is_horizontal() {
+            return None;
+        }
+
+        let (horizontal, vertical) = if a.is_horizontal() { (a, b) } else { (b, a) };
+
+        let ordinal = match (horizontal, vertical) {
+            (Cardinal::East, Cardinal::North) => Ordinal::NorthEast,
+            (Cardinal::West, Cardinal::North) => Ordinal::NorthWest,
+            (Cardinal::West, Cardinal::South) => Ordinal::SouthWest,
+            _ => Ordinal::SouthEast,
+        };
+
+        Some(ordinal)
+    }
+
+    fn index(self) -> usize {
+        match self {
+            Ordinal::East => 0,
+            Ordinal::NorthEast => 1,
+            Ordinal::North => 2,
+            Ordinal::NorthWest => 3,
+            Ordinal::West => 4,
+            Ordinal::SouthWest => 5,
+            Ordinal::South => 6,
+            Ordinal::SouthEast => 7,
+        }
+    }
+
+    fn from_index(index: usize) -> Self {
+        match index {
+            0 => Ordinal::East,
+            1 => Ordinal::NorthEast,
+            2 => Ordinal::North,
+            3 => Ordinal::NorthWest,
+            4 => Ordinal::West,
+            5 => Ordinal::SouthWest,
+            6 => Ordinal::South,
+            7 => Ordinal::SouthEast,
+            _ => unreachable!(),
+        }
+    }
+}
+
+impl From<Cardinal> for Ordinal {
+    fn from(cardinal: Cardinal) -> Self {
+        match cardinal {
+            Cardinal::East => Ordinal::East,
+            Cardinal::North => Ordinal::North,
+            Cardinal::West => Ordinal::West,
+            Cardinal::South => Ordinal::South,
+        }
+    }
+}
+
+impl TryFrom<Ordinal> for Cardinal {
+    /// The diagonal which could not be converted is handed back.
+    type Error = Ordinal;
+
+    fn try_from(ordinal: Ordinal) -> Result<Self, Self::Error> {
+        match ordinal {
+            Ordinal::East => Ok(Cardinal::East),
+            Ordinal::North => Ok(Cardinal::North),
+            Ordinal::West => Ok(Cardinal::West),
+            Ordinal::South => Ok(Cardinal::South),
+            diagonal => Err(diagonal),
+        }
+    }
+}
+
+impl TryFrom<Angle> for Ordinal {
+    type Error = NonFiniteAngle;
+
+    fn try_from(angle: Angle) -> Result<Self, Self::Error> {
+        Self::try_from_angle(angle)
+    }
+}
+
+impl core::fmt::Display for Ordinal {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let word = match self {
+            Ordinal::East => "east",
+            Ordinal::NorthEast => "northeast",
+            Ordinal::North => "north",
+            Ordinal::NorthWest => "northwest",
+            Ordinal::West => "west",
+            Ordinal::SouthWest => "southwest",
+            Ordinal::South => "south",
+            Ordinal::SouthEast => "southeast",
+        };
+
+        f.pad(word)
+    }
+}
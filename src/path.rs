@@ -0,0 +1,236 @@
+use crate::{Cardinal, GridPos};
+
+/// A sequence of steps, each one in a [Cardinal] direction. This is a Freeman chain code,
+/// useful for corridors, outlines and replays.
+///
+/// A path can be parsed from a string of direction letters, each optionally followed by
+/// a repeat count, such as `"NNEESW"` or `"R3 U2"`. The letters `N`, `E`, `S` and `W` name
+/// the cardinals, and `U`, `R`, `D` and `L` name north, east, south and west. Letters are
+/// case-insensitive, and whitespace and commas between steps are ignored. A parsed path
+/// can have at most [MAX_PARSED_STEPS] steps, so a huge count can't exhaust memory.
+///
+/// [MAX_PARSED_STEPS]: CardinalPath::MAX_PARSED_STEPS
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CardinalPath {
+    steps: Vec<Cardinal>,
+}
+
+impl CardinalPath {
+    /// The most steps a path parsed with `FromStr` can have, counting every repeat.
+    pub const MAX_PARSED_STEPS: usize = 1 << 20;
+
+    /// Creates an empty path.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The steps of the path, in order.
+    pub fn steps(&self) -> &[Cardinal] {
+        &self.steps
+    }
+
+    /// Adds a step to the end of the path.
+    pub fn push(&mut self, step: Cardinal) {
+        self.steps.push(step);
+    }
+
+    /// The number of steps in the path.
+    pub fn len(&self) -> usize {
+        self.steps.len()
+    }
+
+    /// Has no steps.
+    pub fn is_empty(&self) -> bool {
+        self.steps.is_empty()
+    }
+
+    /// Gives an iterator over every position on the path, starting with `start` itself.
+    pub fn positions(&self, start: GridPos) -> impl Iterator<Item = GridPos> + '_ {
+        core::iter::once(start).chain(self.steps.iter().scan(start, |pos, step| {
+            *pos = pos.step(*step);
+            Some(*pos)
+        }))
+    }
+
+    /// Gives an iterator over the runs of repeated steps, as a direction and a length.
+    pub fn segments(&self) -> impl Iterator<Item = (Cardinal, u32)> + '_ {
+        self.steps
+            .chunk_by(|a, b| a == b)
+            .map(|run| (run[0], run.len() as u32))
+    }
+
+    /// Returns the position the path ends on, when it starts at `start`.
+    pub fn endpoint(&self, start: GridPos) -> GridPos {
+        self.positions(start).last().unwrap_or(start)
+    }
+
+    /// Returns the smallest and largest corners of the box containing every position
+    /// on the path, when it starts at `start`.
+    pub fn bounds(&self, start: GridPos) -> (GridPos, GridPos) {
+        self.positions(start)
+            .fold((start, start), |(min, max), pos| {
+                (
+                    GridPos::new(min.x.min(pos.x), min.y.min(pos.y)),
+                    GridPos::new(max.x.max(pos.x), max.y.max(pos.y)),
+                )
+            })
+    }
+
+    /// Ends where it starts.
+    pub fn is_closed(&self) -> bool {
+        let origin = GridPos::default();
+
+        self.endpoint(origin) == origin
+    }
+
+    /// Returns the area enclosed by the path, using the shoelace formula. Returns `None` if
+    /// the path is not closed.
+    ///
+    /// The path is assumed not to cross itself.
+    pub fn area(&self) -> Option<u64> {
+        if !self.is_closed() {
+            return None;
+        }
+
+        let positions: Vec<GridPos> = self.positions(GridPos::default()).collect();
+        let twice_area: i64 = positions
+            .windows(2)
+            .map(|pair| pair[0].x as i64 * pair[1].y as i64 - pair[1].x as i64 * pair[0].y as i64)
+            .sum();
+
+        Some(twice_area.unsigned_abs() / 2)
+    }
+
+    /// Returns the number of lattice points strictly inside the path, using Pick's theorem.
+    /// Returns `None` if the path is empty or not closed.
+    ///
+    /// The path is assumed not to cross itself.
+    pub fn interior_points(&self) -> Option<u64> {
+        if self.is_empty() {
+            return None;
+        }
+        let area = self.area()?;
+
+        // Pick's theorem is `area = interior + boundary / 2 - 1`, and every step
+        // of a closed path adds exactly one boundary point.
+        (area + 1).checked_sub(self.len() as u64 / 2)
+    }
+}
+
+impl From<Vec<Cardinal>> for CardinalPath {
+    fn from(steps: Vec<Cardinal>) -> Self {
+        Self { steps }
+    }
+}
+
+impl FromIterator<Cardinal> for CardinalPath {
+    fn from_iter<I: IntoIterator<Item = Cardinal>>(iter: I) -> Self {
+        Self {
+            steps: iter.into_iter().collect(),
+        }
+    }
+}
+
+impl core::str::FromStr for CardinalPath {
+    type Err = ParsePathError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut steps = Vec::new();
+        let mut chars = s.char_indices().peekable();
+
+        while let Some((position, letter)) = chars.next() {
+            if letter.is_whitespace() || letter == ',' {
+                continue;
+            }
+
+            let step = match letter.to_ascii_uppercase() {
+                'E' | 'R' => Cardinal::East,
+                'N' | 'U' => Cardinal::North,
+                'W' | 'L' => Cardinal::West,
+                'S' | 'D' => Cardinal::South,
+                _ => {
+                    return Err(ParsePathError::UnknownDirection {
+                        found: letter,
+                        position,
+                    })
+                }
+            };
+
+            let mut count: Option<usize> = None;
+            while let Some(digit) = chars.peek().and_then(|(_, c)| c.to_digit(10)) {
+                chars.next();
+                count = count
+                    .unwrap_or(0)
+                    .checked_mul(10)
+                    .and_then(|v| v.checked_add(digit as usize))
+                    .map(Some)
+                    .ok_or(ParsePathError::InvalidCount { position })?;
+            }
+
+            let count = count.unwrap_or(1);
+            if count > Self::MAX_PARSED_STEPS - steps.len() {
+                return Err(ParsePathError::InvalidCount { position });
+            }
+            steps.extend(core::iter::repeat_n(step, count));
+        }
+
+        Ok(Self { steps })
+    }
+}
+
+impl core::fmt::Display for CardinalPath {
+    /// Writes the path as a string of direction letters, such as `NNEESW`.
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        for step in &self.steps {
+            let letter = match step {
+                Cardinal::East => 'E',
+                Cardinal::North => 'N',
+                Cardinal::West => 'W',
+                Cardinal::South => 'S',
+            };
+            core::fmt::Write::write_char(f, letter)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// The error returned when a [CardinalPath] can't be parsed.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum ParsePathError {
+    /// A character which isn't a direction letter was found where a step should start.
+    UnknownDirection {
+        /// The character which was found.
+        found: char,
+        /// The byte offset of the character.
+        position: usize,
+    },
+    /// A repeat count was too large, or took the path past [CardinalPath::MAX_PARSED_STEPS].
+    InvalidCount {
+        /// The byte offset of the step the count belongs to.
+        position: usize,
+    },
+}
+
+impl core::fmt::Display for ParsePathError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            ParsePathError::UnknownDirection { found, position } => write!(
+                f,
+                "expected a direction letter at byte {}, but found {:?}",
+                position, found
+            ),
+            ParsePathError::InvalidCount { position } => {
+                write!(
+                    f,
+                    "the repeat count of the step at byte {} takes the path past {} steps",
+                    position,
+                    CardinalPath::MAX_PARSED_STEPS
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for ParsePathError {}
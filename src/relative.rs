@@ -0,0 +1,147 @@
+use core::ops;
+
+use crate::{Cardinal, CardinalValues};
+
+/// A direction relative to a facing, for turtle-style logic which thinks in turns.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum RelativeDirection {
+    /// The same way as the facing.
+    Forward,
+    /// A quarter turn counter-clockwise from the facing.
+    Left,
+    /// The opposite way to the facing.
+    Back,
+    /// A quarter turn clockwise from the facing.
+    Right,
+}
+
+impl RelativeDirection {
+    /// Gives an iterator over the four relative directions, in counter-clockwise order
+    /// starting from forward.
+    pub fn iter_values() -> impl Iterator<Item = Self> {
+        [
+            RelativeDirection::Forward,
+            RelativeDirection::Left,
+            RelativeDirection::Back,
+            RelativeDirection::Right,
+        ]
+        .into_iter()
+    }
+
+    /// The number of counter-clockwise quarter turns this direction is from forward,
+    /// in the same sense as [Cardinal::rotate].
+    pub fn quarter_turns(self) -> i32 {
+        match self {
+            RelativeDirection::Forward => 0,
+            RelativeDirection::Left => 1,
+            RelativeDirection::Back => 2,
+            RelativeDirection::Right => 3,
+        }
+    }
+}
+
+impl Cardinal {
+    /// Turns from this facing in a relative direction, so `Cardinal::North.turn(RelativeDirection::Left)`
+    /// is `Cardinal::West`.
+    #[must_use = "this returns the result of the operation, \
+    without modifying the original"]
+    pub fn turn(self, direction: RelativeDirection) -> Self {
+        self.rotate(direction.quarter_turns())
+    }
+
+    /// Returns which way this cardinal is as seen from `facing`, so that
+    /// `facing.turn(self.relative_to(facing)) == self`.
+    pub fn relative_to(self, facing: Cardinal) -> RelativeDirection {
+        RelativeDirection::iter_values()
+            .find(|v| facing.turn(*v) == self)
+            .expect("every cardinal is some turn away from every other")
+    }
+}
+
+impl core::fmt::Display for RelativeDirection {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let word = match self {
+            RelativeDirection::Forward => "forward",
+            RelativeDirection::Left => "left",
+            RelativeDirection::Back => "back",
+            RelativeDirection::Right => "right",
+        };
+
+        f.pad(word)
+    }
+}
+
+/// A struct which a value assigned to each relative direction. This is a [CardinalValues]
+/// as seen from a facing.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, PartialOrd, Ord, Default, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RelativeValues<T> {
+    /// The value assigned to forward.
+    pub forward: T,
+    /// The value assigned to left.
+    pub left: T,
+    /// The value assigned to back.
+    pub back: T,
+    /// The value assigned to right.
+    pub right: T,
+}
+
+impl<T> RelativeValues<T> {
+    /// Converts a [RelativeValues] from one type to another.
+    pub fn map<B, F>(self, mut f: F) -> RelativeValues<B>
+    where
+        F: FnMut(T) -> B,
+    {
+        RelativeValues {
+            forward: f(self.forward),
+            left: f(self.left),
+            back: f(self.back),
+            right: f(self.right),
+        }
+    }
+
+    /// Converts back to absolute directions, with forward pointing towards `facing`.
+    pub fn to_absolute(self, facing: Cardinal) -> CardinalValues<T> {
+        let mut array = [self.forward, self.left, self.back, self.right];
+        // forward lands on `facing`, and each following value one quarter turn further on.
+        array.rotate_right(facing.relative_to(Cardinal::East).quarter_turns() as usize);
+
+        let [east, north, west, south] = array;
+        CardinalValues {
+            east,
+            north,
+            west,
+            south,
+        }
+    }
+}
+
+impl<T> CardinalValues<T> {
+    /// Converts to directions relative to `facing`, so the value at `facing` becomes `forward`.
+    pub fn to_relative(self, facing: Cardinal) -> RelativeValues<T> {
+        let mut array = [self.east, self.north, self.west, self.south];
+        array.rotate_left(facing.relative_to(Cardinal::East).quarter_turns() as usize);
+
+        let [forward, left, back, right] = array;
+        RelativeValues {
+            forward,
+            left,
+            back,
+            right,
+        }
+    }
+}
+
+impl<T> ops::Index<RelativeDirection> for RelativeValues<T> {
+    type Output = T;
+
+    fn index(&self, index: RelativeDirection) -> &Self::Output {
+        match index {
+            RelativeDirection::Forward => &self.forward,
+            RelativeDirection::Left => &self.left,
+            RelativeDirection::Back => &self.back,
+            RelativeDirection::Right => &self.right,
+        }
+    }
+}
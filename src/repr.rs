@@ -0,0 +1,202 @@
+//! Alternative serde representations for [Cardinal] and [CardinalValues], for use with
+//! `#[serde(with = "...")]`.
+//!
+//! ```
+//! # use cardinal_values::{Cardinal, CardinalValues};
+//! #[derive(serde::Serialize, serde::Deserialize)]
+//! struct Door {
+//!     #[serde(with = "cardinal_values::repr::lowercase")]
+//!     facing: Cardinal,
+//!     #[serde(with = "cardinal_values::repr::shorthand")]
+//!     padding: CardinalValues<f32>,
+//! }
+//! ```
+//!
+//! Every string representation of a [Cardinal] deserializes from any of the names accepted
+//! by its `FromStr`, in any case, so `"north"`, `"North"`, `"N"` and `"^"` are all north.
+
+use core::{fmt, marker::PhantomData};
+
+use serde::de::{self, Deserializer, SeqAccess, Unexpected, Visitor};
+use serde::ser::{SerializeTuple, Serializer};
+
+use crate::{Cardinal, CardinalFormat, CardinalValues};
+
+struct CardinalVisitor;
+
+impl<'de> Visitor<'de> for CardinalVisitor {
+    type Value = Cardinal;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("a cardinal name, letter, arrow or index")
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+        v.parse()
+            .map_err(|_| E::invalid_value(Unexpected::Str(v), &self))
+    }
+
+    fn visit_char<E: de::Error>(self, v: char) -> Result<Self::Value, E> {
+        Cardinal::try_from(v).map_err(|_| E::invalid_value(Unexpected::Char(v), &self))
+    }
+
+    fn visit_u64<E: de::Error>(self, v: u64) -> Result<Self::Value, E> {
+        usize::try_from(v)
+            .ok()
+            .and_then(|v| Cardinal::iter_values().nth(v))
+            .ok_or_else(|| E::invalid_value(Unexpected::Unsigned(v), &self))
+    }
+
+    fn visit_i64<E: de::Error>(self, v: i64) -> Result<Self::Value, E> {
+        u64::try_from(v)
+            .map_err(|_| E::invalid_value(Unexpected::Signed(v), &self))
+            .and_then(|v| self.visit_u64(v))
+    }
+}
+
+/// Writes a [Cardinal] as a lowercase word, such as `"north"`.
+pub mod lowercase {
+    use super::*;
+
+    /// Serializes a [Cardinal] as a lowercase word.
+    pub fn serialize<S: Serializer>(value: &Cardinal, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(&value.display(CardinalFormat::Word))
+    }
+
+    /// Deserializes a [Cardinal] from any of its names.
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Cardinal, D::Error> {
+        deserializer.deserialize_str(CardinalVisitor)
+    }
+}
+
+/// Writes a [Cardinal] as a single uppercase letter, such as `"N"`.
+pub mod letter {
+    use super::*;
+
+    /// Serializes a [Cardinal] as a single uppercase letter.
+    pub fn serialize<S: Serializer>(value: &Cardinal, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(&value.display(CardinalFormat::Letter))
+    }
+
+    /// Deserializes a [Cardinal] from any of its names.
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Cardinal, D::Error> {
+        deserializer.deserialize_str(CardinalVisitor)
+    }
+}
+
+/// Writes a [Cardinal] as a `u8`, counting counter-clockwise from east: east is 0, north is 1,
+/// west is 2 and south is 3.
+pub mod index {
+    use super::*;
+
+    /// Serializes a [Cardinal] as a `u8`.
+    pub fn serialize<S: Serializer>(value: &Cardinal, serializer: S) -> Result<S::Ok, S::Error> {
+        let index = Cardinal::iter_values()
+            .position(|v| v == *value)
+            .expect("every cardinal is in `iter_values`");
+
+        serializer.serialize_u8(index as u8)
+    }
+
+    /// Deserializes a [Cardinal] from a `u8`.
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Cardinal, D::Error> {
+        deserializer.deserialize_u8(CardinalVisitor)
+    }
+}
+
+/// Writes a [CardinalValues] as a four element array, in the order `[east, north, west, south]`.
+pub mod array {
+    use super::*;
+
+    /// Serializes a [CardinalValues] as a four element array.
+    pub fn serialize<S, T>(value: &CardinalValues<T>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+        T: serde::Serialize,
+    {
+        let mut tuple = serializer.serialize_tuple(4)?;
+        tuple.serialize_element(&value.east)?;
+        tuple.serialize_element(&value.north)?;
+        tuple.serialize_element(&value.west)?;
+        tuple.serialize_element(&value.south)?;
+
+        tuple.end()
+    }
+
+    /// Deserializes a [CardinalValues] from a four element array.
+    pub fn deserialize<'de, D, T>(deserializer: D) -> Result<CardinalValues<T>, D::Error>
+    where
+        D: Deserializer<'de>,
+        T: serde::Deserialize<'de>,
+    {
+        deserializer.deserialize_tuple(4, ArrayVisitor(PhantomData))
+    }
+
+    struct ArrayVisitor<T>(PhantomData<T>);
+
+    impl<'de, T: serde::Deserialize<'de>> Visitor<'de> for ArrayVisitor<T> {
+        type Value = CardinalValues<T>;
+
+        fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.write_str("an array of four values, in the order [east, north, west, south]")
+        }
+
+        fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+            let mut next = |index| {
+                seq.next_element()?
+                    .ok_or_else(|| de::Error::invalid_length(index, &self))
+            };
+
+            Ok(CardinalValues {
+                east: next(0)?,
+                north: next(1)?,
+                west: next(2)?,
+                south: next(3)?,
+            })
+        }
+    }
+}
+
+/// Writes a [CardinalValues] as a CSS-style shorthand string, such as `"1 2 3 4"`, with the
+/// values in the order north, east, south, west. Reading accepts the one, two and three value
+/// forms too, and writing uses the shortest form which keeps every value.
+pub mod shorthand {
+    use super::*;
+
+    /// Serializes a [CardinalValues] as a shorthand string.
+    pub fn serialize<S, T>(value: &CardinalValues<T>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+        T: fmt::Display + PartialEq,
+    {
+        serializer.collect_str(&crate::shorthand::format(value))
+    }
+
+    /// Deserializes a [CardinalValues] from a shorthand string.
+    pub fn deserialize<'de, D, T>(deserializer: D) -> Result<CardinalValues<T>, D::Error>
+    where
+        D: Deserializer<'de>,
+        T: core::str::FromStr + Clone,
+        T::Err: fmt::Display,
+    {
+        deserializer.deserialize_str(ShorthandVisitor(PhantomData))
+    }
+
+    struct ShorthandVisitor<T>(PhantomData<T>);
+
+    impl<T> Visitor<'_> for ShorthandVisitor<T>
+    where
+        T: core::str::FromStr + Clone,
+        T::Err: fmt::Display,
+    {
+        type Value = CardinalValues<T>;
+
+        fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.write_str("one to four space separated values, in the order north, east, south, west")
+        }
+
+        fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+            crate::shorthand::parse(v).map_err(E::custom)
+        }
+    }
+}
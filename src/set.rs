@@ -0,0 +1,233 @@
+use core::ops;
+
+use crate::{Cardinal, CardinalValues};
+
+/// A set of cardinals, packed into the low four bits of a `u8`.
+///
+/// Each cardinal has one bit, counting counter-clockwise from east: east is `0b0001`,
+/// north is `0b0010`, west is `0b0100` and south is `0b1000`.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, PartialOrd, Ord, Default, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "u8", into = "u8"))]
+pub struct CardinalSet(u8);
+
+impl CardinalSet {
+    /// The set with no cardinals in it.
+    pub const EMPTY: CardinalSet = CardinalSet(0);
+    /// The set with every cardinal in it.
+    pub const ALL: CardinalSet = CardinalSet(0b1111);
+
+    fn bit(cardinal: Cardinal) -> u8 {
+        match cardinal {
+            Cardinal::East => 0b0001,
+            Cardinal::North => 0b0010,
+            Cardinal::West => 0b0100,
+            Cardinal::South => 0b1000,
+        }
+    }
+
+    /// Returns the raw bits of the set.
+    pub fn bits(self) -> u8 {
+        self.0
+    }
+
+    /// Creates a set from raw bits. Returns `None` if any bit above the low four is set.
+    pub fn from_bits(bits: u8) -> Option<Self> {
+        (bits <= Self::ALL.0).then_some(Self(bits))
+    }
+
+    /// Returns a stable index in `0..16`, suitable for lookup tables. This is the same as [bits].
+    ///
+    /// [bits]: CardinalSet::bits
+    pub fn index(self) -> usize {
+        self.0 as usize
+    }
+
+    /// Creates a set from an index in `0..16`. Returns `None` if the index is out of range.
+    pub fn from_index(index: usize) -> Option<Self> {
+        u8::try_from(index).ok().and_then(Self::from_bits)
+    }
+
+    /// Does the set have this cardinal in it.
+    pub fn contains(self, cardinal: Cardinal) -> bool {
+        self.0 & Self::bit(cardinal) != 0
+    }
+
+    /// Adds a cardinal to the set. Returns whether it was newly added.
+    pub fn insert(&mut self, cardinal: Cardinal) -> bool {
+        let added = !self.contains(cardinal);
+        self.0 |= Self::bit(cardinal);
+
+        added
+    }
+
+    /// Removes a cardinal from the set. Returns whether it was present.
+    pub fn remove(&mut self, cardinal: Cardinal) -> bool {
+        let present = self.contains(cardinal);
+        self.0 &= !Self::bit(cardinal);
+
+        present
+    }
+
+    /// The number of cardinals in the set.
+    pub fn len(self) -> usize {
+        self.0.count_ones() as usize
+    }
+
+    /// Has no cardinals in it.
+    pub fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+
+    /// The cardinals in either set.
+    #[must_use = "this returns the result of the operation, \
+    without modifying the original"]
+    pub fn union(self, other: CardinalSet) -> Self {
+        Self(self.0 | other.0)
+    }
+
+    /// The cardinals in both sets.
+    #[must_use = "this returns the result of the operation, \
+    without modifying the original"]
+    pub fn intersection(self, other: CardinalSet) -> Self {
+        Self(self.0 & other.0)
+    }
+
+    /// The cardinals in this set but not the other.
+    #[must_use = "this returns the result of the operation, \
+    without modifying the original"]
+    pub fn difference(self, other: CardinalSet) -> Self {
+        Self(self.0 & !other.0)
+    }
+
+    /// The cardinals not in this set.
+    #[must_use = "this returns the result of the operation, \
+    without modifying the original"]
+    pub fn complement(self) -> Self {
+        Self(!self.0 & Self::ALL.0)
+    }
+
+    /// Rotates every cardinal in the set, the same as [Cardinal::rotate].
+    #[must_use = "this returns the result of the operation, \
+    without modifying the original"]
+    pub fn rotate(self, amount: i32) -> Self {
+        let amount = amount.rem_euclid(4) as u32;
+
+        Self(((self.0 << amount) | (self.0 >> (4 - amount))) & Self::ALL.0)
+    }
+
+    /// Gives an iterator over the cardinals in the set, in the order of [Cardinal::iter_values].
+    pub fn iter(self) -> impl Iterator<Item = Cardinal> {
+        Cardinal::iter_values().filter(move |v| self.contains(*v))
+    }
+}
+
+impl From<Cardinal> for CardinalSet {
+    fn from(cardinal: Cardinal) -> Self {
+        Self(Self::bit(cardinal))
+    }
+}
+
+impl From<CardinalValues<bool>> for CardinalSet {
+    fn from(values: CardinalValues<bool>) -> Self {
+        Cardinal::iter_values().filter(|v| values[*v]).collect()
+    }
+}
+
+impl From<CardinalSet> for CardinalValues<bool> {
+    fn from(set: CardinalSet) -> Self {
+        CardinalValues {
+            east: set.contains(Cardinal::East),
+            north: set.contains(Cardinal::North),
+            west: set.contains(Cardinal::West),
+            south: set.contains(Cardinal::South),
+        }
+    }
+}
+
+impl TryFrom<u8> for CardinalSet {
+    type Error = InvalidSetBits;
+
+    /// Creates a set from raw bits, like [CardinalSet::from_bits].
+    fn try_from(bits: u8) -> Result<Self, Self::Error> {
+        Self::from_bits(bits).ok_or(InvalidSetBits(bits))
+    }
+}
+
+impl From<CardinalSet> for u8 {
+    fn from(set: CardinalSet) -> Self {
+        set.bits()
+    }
+}
+
+impl FromIterator<Cardinal> for CardinalSet {
+    fn from_iter<I: IntoIterator<Item = Cardinal>>(iter: I) -> Self {
+        let mut set = Self::EMPTY;
+        for cardinal in iter {
+            set.insert(cardinal);
+        }
+
+        set
+    }
+}
+
+impl ops::BitOr for CardinalSet {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self::Output {
+        self.union(rhs)
+    }
+}
+
+impl ops::BitOrAssign for CardinalSet {
+    fn bitor_assign(&mut self, rhs: Self) {
+        *self = self.union(rhs);
+    }
+}
+
+impl ops::BitAnd for CardinalSet {
+    type Output = Self;
+
+    fn bitand(self, rhs: Self) -> Self::Output {
+        self.intersection(rhs)
+    }
+}
+
+impl ops::BitAndAssign for CardinalSet {
+    fn bitand_assign(&mut self, rhs: Self) {
+        *self = self.intersection(rhs);
+    }
+}
+
+impl ops::Sub for CardinalSet {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.difference(rhs)
+    }
+}
+
+impl ops::Not for CardinalSet {
+    type Output = Self;
+
+    fn not(self) -> Self::Output {
+        self.complement()
+    }
+}
+
+/// The error returned when raw bits have a bit set above the low four, so can't be read
+/// as a [CardinalSet]. It holds the bits which were given.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub struct InvalidSetBits(pub u8);
+
+impl core::fmt::Display for InvalidSetBits {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "{:#010b} is not a cardinal set; only the low four bits may be set",
+            self.0
+        )
+    }
+}
+
+impl std::error::Error for InvalidSetBits {}
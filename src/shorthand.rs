@@ -0,0 +1,235 @@
+//! Parsing and formatting of CSS-style shorthand for [CardinalValues], as used for padding,
+//! margins and borders.
+//!
+//! A shorthand is one to four values separated by whitespace, read the way CSS reads them,
+//! with top, right, bottom and left mapped onto north, east, south and west:
+//!
+//! | shorthand   | north | east | south | west |
+//! |-------------|-------|------|-------|------|
+//! | `"4"`       | 4     | 4    | 4     | 4    |
+//! | `"4 8"`     | 4     | 8    | 4     | 8    |
+//! | `"4 8 2"`   | 4     | 8    | 2     | 8    |
+//! | `"4 8 2 1"` | 4     | 8    | 2     | 1    |
+//!
+//! ```
+//! # use cardinal_values::shorthand::{self, Length, LengthUnit};
+//! let padding = shorthand::parse::<Length>("4px 50%").unwrap();
+//! assert_eq!(padding.north, Length::new(4.0, LengthUnit::Px));
+//! assert_eq!(padding.west, Length::new(50.0, LengthUnit::Percent));
+//! assert_eq!(shorthand::format(&padding), "4px 50%");
+//! ```
+
+use core::{fmt, str::FromStr};
+
+use crate::CardinalValues;
+
+/// Parses a shorthand, reading each value with its `FromStr`.
+pub fn parse<T>(s: &str) -> Result<CardinalValues<T>, ShorthandError<T::Err>>
+where
+    T: FromStr + Clone,
+{
+    parse_with(s, T::from_str)
+}
+
+/// Parses a shorthand, reading each value with `f`.
+pub fn parse_with<T, E, F>(s: &str, mut f: F) -> Result<CardinalValues<T>, ShorthandError<E>>
+where
+    T: Clone,
+    F: FnMut(&str) -> Result<T, E>,
+{
+    let mut values = Vec::with_capacity(4);
+    for (index, token) in s.split_whitespace().enumerate() {
+        // `split_whitespace` gives slices of `s`, so this is the token's byte offset.
+        let position = token.as_ptr() as usize - s.as_ptr() as usize;
+        if index == 4 {
+            return Err(ShorthandError::TooManyValues { position });
+        }
+
+        let value = f(token).map_err(|error| ShorthandError::InvalidValue {
+            token: token.to_string(),
+            position,
+            error,
+        })?;
+        values.push(value);
+    }
+
+    let (north, east, south, west) = match values.as_slice() {
+        [all] => (all, all, all, all),
+        [vertical, horizontal] => (vertical, horizontal, vertical, horizontal),
+        [north, horizontal, south] => (north, horizontal, south, horizontal),
+        [north, east, south, west] => (north, east, south, west),
+        _ => return Err(ShorthandError::Empty),
+    };
+
+    Ok(CardinalValues {
+        east: east.clone(),
+        north: north.clone(),
+        west: west.clone(),
+        south: south.clone(),
+    })
+}
+
+/// Formats values as the shortest shorthand which keeps every value.
+pub fn format<T: fmt::Display + PartialEq>(values: &CardinalValues<T>) -> String {
+    let CardinalValues {
+        east,
+        north,
+        west,
+        south,
+    } = values;
+
+    if east != west {
+        format!("{} {} {} {}", north, east, south, west)
+    } else if north != south {
+        format!("{} {} {}", north, east, south)
+    } else if north != east {
+        format!("{} {}", north, east)
+    } else {
+        format!("{}", north)
+    }
+}
+
+/// The error returned when a shorthand can't be parsed. `E` is the error of the value parser.
+#[derive(Debug, PartialEq, Eq, Clone, Hash)]
+pub enum ShorthandError<E> {
+    /// The shorthand had no values.
+    Empty,
+    /// The shorthand had more than four values.
+    TooManyValues {
+        /// The byte offset of the fifth value.
+        position: usize,
+    },
+    /// A value couldn't be parsed.
+    InvalidValue {
+        /// The text of the value.
+        token: String,
+        /// The byte offset of the value.
+        position: usize,
+        /// The error from the value parser.
+        error: E,
+    },
+}
+
+impl<E: fmt::Display> fmt::Display for ShorthandError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ShorthandError::Empty => f.write_str("expected one to four values, but found none"),
+            ShorthandError::TooManyValues { position } => write!(
+                f,
+                "expected at most four values, but found a fifth at byte {}",
+                position
+            ),
+            ShorthandError::InvalidValue {
+                token,
+                position,
+                error,
+            } => write!(
+                f,
+                "invalid value {:?} at byte {}: {}",
+                token, position, error
+            ),
+        }
+    }
+}
+
+impl<E: fmt::Debug + fmt::Display> std::error::Error for ShorthandError<E> {}
+
+/// The unit of a [Length].
+#[derive(Debug, PartialEq, Eq, Clone, Copy, PartialOrd, Ord, Default, Hash)]
+pub enum LengthUnit {
+    /// No unit, such as `0` or `1.5`.
+    #[default]
+    None,
+    /// Pixels, such as `4px`.
+    Px,
+    /// A percentage, such as `50%`.
+    Percent,
+    /// A multiple of the font size, such as `1.5em`.
+    Em,
+}
+
+impl LengthUnit {
+    fn suffix(self) -> &'static str {
+        match self {
+            LengthUnit::None => "",
+            LengthUnit::Px => "px",
+            LengthUnit::Percent => "%",
+            LengthUnit::Em => "em",
+        }
+    }
+}
+
+/// A number with an optional unit, such as `4px`, `50%` or `1.5em`, for use as a shorthand value.
+#[derive(Debug, PartialEq, Clone, Copy, PartialOrd, Default)]
+pub struct Length {
+    /// The number.
+    pub value: f32,
+    /// The unit the number is in.
+    pub unit: LengthUnit,
+}
+
+impl Length {
+    /// Creates a new length.
+    pub const fn new(value: f32, unit: LengthUnit) -> Self {
+        Self { value, unit }
+    }
+}
+
+impl FromStr for Length {
+    type Err = ParseLengthError;
+
+    /// Reads a finite number followed by an optional unit, in any case, such as `4px`,
+    /// `50%` or `1.5EM`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let lower = s.to_ascii_lowercase();
+        let number = |text: &str| text.parse::<f32>().ok().filter(|v| v.is_finite());
+
+        // a suffix only counts as the unit if what's before it is a number, so `4rem`
+        // isn't read as `4r` in `em`.
+        for unit in [
+            LengthUnit::Px,
+            LengthUnit::Percent,
+            LengthUnit::Em,
+            LengthUnit::None,
+        ] {
+            if let Some(value) = lower.strip_suffix(unit.suffix()).and_then(number) {
+                return Ok(Self { value, unit });
+            }
+        }
+
+        let unit_start = lower
+            .find(|c: char| c.is_ascii_alphabetic() || c == '%')
+            .unwrap_or(lower.len());
+        if unit_start > 0 && number(&lower[..unit_start]).is_some() {
+            Err(ParseLengthError::UnknownUnit)
+        } else {
+            Err(ParseLengthError::InvalidNumber)
+        }
+    }
+}
+
+impl fmt::Display for Length {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}{}", self.value, self.unit.suffix())
+    }
+}
+
+/// The error returned when a [Length] can't be parsed.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum ParseLengthError {
+    /// The number before the unit isn't a valid, finite number.
+    InvalidNumber,
+    /// The unit isn't one of `px`, `%` or `em`.
+    UnknownUnit,
+}
+
+impl fmt::Display for ParseLengthError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseLengthError::InvalidNumber => f.write_str("expected a finite number"),
+            ParseLengthError::UnknownUnit => f.write_str("expected a unit of px, % or em"),
+        }
+    }
+}
+
+impl std::error::Error for ParseLengthError {}